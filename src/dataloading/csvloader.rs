@@ -0,0 +1,243 @@
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+use crate::dataloading::songinfo::SongInfo;
+
+/// Prefix of the single field that marks a section header line.
+const SECTION_PREFIX: char = '#';
+/// Number of fields every song line has, title, artist and dance. The
+/// album, key, bpm and file follow where a song has them.
+const REQUIRED_FIELDS: usize = 3;
+
+pub fn save_songs_to_csv(path: &Path, songs: &[SongInfo]) -> Result<()> {
+    std::fs::write(path, songs_to_delimited(songs, ','))
+}
+
+pub fn load_songs_from_csv(path: &Path) -> Result<Vec<SongInfo>> {
+    Ok(songs_from_delimited(&std::fs::read_to_string(path)?, ','))
+}
+
+/// Serializes the title, artist, dance, album, key, bpm and file of each song
+/// as one line per song, quoting fields where necessary and leaving out empty
+/// trailing fields. Section headers are written as a single field holding
+/// their title prefixed with `#`.
+pub fn songs_to_delimited(songs: &[SongInfo], delimiter: char) -> String {
+    let mut content = String::new();
+
+    for song in songs {
+        if song.is_section {
+            content.push_str(&quote_field(
+                &format!("{}{}", SECTION_PREFIX, song.title),
+                delimiter,
+            ));
+            content.push('\n');
+            continue;
+        }
+
+        let mut fields = vec![
+            song.title.clone(),
+            song.artist.clone(),
+            song.dance.clone(),
+            song.album.clone(),
+            song.key.clone(),
+            song.bpm.map(|bpm| bpm.to_string()).unwrap_or_default(),
+            song.file_path
+                .as_ref()
+                .map(|path| path.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        ];
+        while fields.len() > REQUIRED_FIELDS && fields.last().is_some_and(|f| f.is_empty()) {
+            fields.pop();
+        }
+
+        let fields: Vec<String> = fields.iter().map(|f| quote_field(f, delimiter)).collect();
+        content.push_str(&fields.join(&delimiter.to_string()));
+        content.push('\n');
+    }
+
+    content
+}
+
+/// Parses delimiter separated lines into songs. Fields are read as title, artist,
+/// dance, album, key, bpm and file; lines without the delimiter are read as "Artist - Title",
+/// or as section header if they start with `#`.
+pub fn songs_from_delimited(content: &str, delimiter: char) -> Vec<SongInfo> {
+    parse_delimited(content, delimiter)
+        .into_iter()
+        .map(|fields| {
+            if fields.len() == 1 {
+                if let Some(title) = fields[0].trim().strip_prefix(SECTION_PREFIX) {
+                    return SongInfo::section(title.trim().to_owned());
+                }
+
+                return song_from_artist_title(&fields[0]);
+            }
+
+            let mut fields = fields.into_iter().map(|f| f.trim().to_owned());
+            SongInfo {
+                title: fields.next().unwrap_or_default(),
+                artist: fields.next().unwrap_or_default(),
+                dance: fields.next().unwrap_or_default(),
+                album: fields.next().unwrap_or_default(),
+                key: fields.next().unwrap_or_default(),
+                bpm: fields.next().and_then(|bpm| bpm.parse().ok()),
+                file_path: fields
+                    .next()
+                    .filter(|path| !path.is_empty())
+                    .map(PathBuf::from),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn song_from_artist_title(line: &str) -> SongInfo {
+    let (artist, title) = match line.split_once(" - ") {
+        Some((artist, title)) => (artist.trim(), title.trim()),
+        None => ("", line.trim()),
+    };
+
+    SongInfo {
+        title: title.to_owned(),
+        artist: artist.to_owned(),
+        ..Default::default()
+    }
+}
+
+/// Splits the content into lines of fields, honoring double quoted fields
+/// which may contain the delimiter, line breaks and escaped quotes.
+/// Empty lines are skipped.
+pub fn parse_delimited(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                fields.push(std::mem::take(&mut field));
+                push_row(&mut rows, std::mem::take(&mut fields));
+            }
+            c => field.push(c),
+        }
+    }
+
+    fields.push(field);
+    push_row(&mut rows, fields);
+
+    rows
+}
+
+fn push_row(rows: &mut Vec<Vec<String>>, fields: Vec<String>) {
+    if fields.iter().any(|f| !f.trim().is_empty()) {
+        rows.push(fields);
+    }
+}
+
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::csvloader::{
+        parse_delimited, songs_from_delimited, songs_to_delimited,
+    };
+    use crate::dataloading::songinfo::SongInfo;
+
+    #[test]
+    fn delimited_fields_are_quoted() {
+        let songs = vec![
+            SongInfo::new(1, "Title".into(), "Artist".into(), "Rumba".into(), None),
+            SongInfo::new(
+                2,
+                "Hello, \"World\"".into(),
+                "A".into(),
+                "Jive".into(),
+                None,
+            ),
+        ];
+
+        assert_eq!(
+            songs_to_delimited(&songs, ','),
+            "Title,Artist,Rumba\n\"Hello, \"\"World\"\"\",A,Jive\n"
+        );
+    }
+
+    #[test]
+    fn delimited_round_trip() {
+        let songs = vec![
+            SongInfo::new(
+                0,
+                "Hello\tWorld".into(),
+                "\"A\"".into(),
+                "Jive".into(),
+                None,
+            ),
+            SongInfo::new(0, "Multi\nLine".into(), "B".into(), "".into(), None),
+        ];
+
+        let parsed = songs_from_delimited(&songs_to_delimited(&songs, '\t'), '\t');
+
+        assert_eq!(parsed, songs);
+    }
+
+    #[test]
+    fn file_and_metadata_survive_round_trip() {
+        let song = SongInfo {
+            album: "Album".into(),
+            bpm: Some(98.5),
+            file_path: Some("/music/a, b.mp3".into()),
+            ..SongInfo::new(0, "Title".into(), "Artist".into(), "Rumba".into(), None)
+        };
+
+        let content = songs_to_delimited(std::slice::from_ref(&song), ',');
+        assert_eq!(
+            content,
+            "Title,Artist,Rumba,Album,,98.5,\"/music/a, b.mp3\"\n"
+        );
+        assert_eq!(songs_from_delimited(&content, ','), vec![song]);
+    }
+
+    #[test]
+    fn plain_lines_are_read_as_artist_and_title() {
+        let parsed = songs_from_delimited("Ed Sheeran - Perfect\r\n\nHavana\n", '\t');
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].artist, "Ed Sheeran");
+        assert_eq!(parsed[0].title, "Perfect");
+        assert_eq!(parsed[1].artist, "");
+        assert_eq!(parsed[1].title, "Havana");
+        assert_eq!(parse_delimited("Rumba\nJive", '\t').len(), 2);
+    }
+
+    #[test]
+    fn sections_survive_round_trip() {
+        let songs = vec![
+            SongInfo::section("Latein 1".into()),
+            SongInfo::new(0, "Title".into(), "Artist".into(), "Rumba".into(), None),
+        ];
+
+        assert_eq!(
+            songs_from_delimited(&songs_to_delimited(&songs, ','), ','),
+            songs
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::dataloading::dances::ENGLISH_DANCE_NAMES;
+use serde::{Deserialize, Serialize};
+
+/// Second name of a dance, shown below the name from the playlist on song
+/// windows with bilingual dance names.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DanceTranslation {
+    pub dance: String,
+    pub translation: String,
+}
+
+impl DanceTranslation {
+    pub fn new(dance: &str, translation: &str) -> Self {
+        Self {
+            dance: dance.to_owned(),
+            translation: translation.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanceTranslations {
+    pub entries: Vec<DanceTranslation>,
+}
+
+impl Default for DanceTranslations {
+    fn default() -> Self {
+        Self {
+            entries: ENGLISH_DANCE_NAMES
+                .iter()
+                .map(|(dance, translation)| DanceTranslation::new(dance, translation))
+                .collect(),
+        }
+    }
+}
+
+impl DanceTranslations {
+    /// Returns the other name of the dance, ignoring case and surrounding
+    /// whitespace. The table is used in both directions, so playlists with
+    /// translated dance names show the original name. Cleared translations
+    /// are left out.
+    pub fn translate(&self, dance: &str) -> Option<&str> {
+        let dance = dance.trim();
+        if dance.is_empty() {
+            return None;
+        }
+
+        self.entries
+            .iter()
+            .find_map(|e| {
+                if e.dance.trim().eq_ignore_ascii_case(dance) {
+                    Some(e.translation.trim())
+                } else if e.translation.trim().eq_ignore_ascii_case(dance) {
+                    Some(e.dance.trim())
+                } else {
+                    None
+                }
+            })
+            .filter(|t| !t.is_empty())
+    }
+
+    /// Adds a translation unless either name is empty or the dance already has one.
+    /// Returns whether it was added.
+    pub fn add(&mut self, dance: &str, translation: &str) -> bool {
+        let dance = dance.trim();
+        let translation = translation.trim();
+        if dance.is_empty()
+            || translation.is_empty()
+            || self
+                .entries
+                .iter()
+                .any(|e| e.dance.trim().eq_ignore_ascii_case(dance))
+        {
+            return false;
+        }
+
+        self.entries.push(DanceTranslation::new(dance, translation));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dance_translations::DanceTranslations;
+
+    #[test]
+    fn translations_work_in_both_directions() {
+        let mut translations = DanceTranslations::default();
+        assert!(translations.add("Quickstep", " Quick Step "));
+        assert!(!translations.add("quickstep", "Quick"));
+
+        assert_eq!(
+            translations.translate(" langsamer walzer"),
+            Some("Slow Waltz")
+        );
+        assert_eq!(
+            translations.translate("Slow Waltz"),
+            Some("Langsamer Walzer")
+        );
+        assert_eq!(translations.translate("Quickstep"), Some("Quick Step"));
+        assert_eq!(translations.translate("Rumba"), None);
+
+        translations.entries[0].translation = " ".to_owned();
+        let dance = translations.entries[0].dance.clone();
+        assert_eq!(translations.translate(&dance), None);
+    }
+}
@@ -0,0 +1,97 @@
+/// Canonical names of common ballroom and social dances.
+pub const KNOWN_DANCES: &[&str] = &[
+    "Langsamer Walzer",
+    "Tango",
+    "Wiener Walzer",
+    "Slowfox",
+    "Quickstep",
+    "Samba",
+    "Cha Cha Cha",
+    "Rumba",
+    "Paso Doble",
+    "Jive",
+    "Discofox",
+    "Foxtrott",
+    "Salsa",
+    "Rock 'n' Roll",
+    "Bachata",
+    "Merengue",
+    "Mambo",
+    "Tango Argentino",
+    "West Coast Swing",
+    "Lindy Hop",
+    "Boogie Woogie",
+    "Polka",
+];
+
+/// Competition categories that group the ballroom dances.
+pub const DANCE_CATEGORIES: &[(&str, &[&str])] = &[
+    (
+        "Standard",
+        &[
+            "Langsamer Walzer",
+            "Tango",
+            "Wiener Walzer",
+            "Slowfox",
+            "Quickstep",
+        ],
+    ),
+    (
+        "Latein",
+        &["Samba", "Cha Cha Cha", "Rumba", "Paso Doble", "Jive"],
+    ),
+];
+
+/// English names of the dances whose German name differs.
+pub const ENGLISH_DANCE_NAMES: &[(&str, &str)] = &[
+    ("Langsamer Walzer", "Slow Waltz"),
+    ("Wiener Walzer", "Viennese Waltz"),
+    ("Slowfox", "Slow Foxtrot"),
+    ("Foxtrott", "Foxtrot"),
+    ("Discofox", "Disco Fox"),
+    ("Tango Argentino", "Argentine Tango"),
+];
+
+/// Returns the category of the dance, ignoring case and surrounding whitespace.
+pub fn dance_category(dance: &str) -> Option<&'static str> {
+    let dance = dance.trim();
+
+    DANCE_CATEGORIES
+        .iter()
+        .find(|(_, dances)| dances.iter().any(|d| d.eq_ignore_ascii_case(dance)))
+        .map(|(category, _)| *category)
+}
+
+/// Merges the given dance name lists, dropping empty names and
+/// duplicates that only differ in case or surrounding whitespace.
+/// Earlier lists take precedence, so their spelling is kept.
+pub fn merge_dance_names<'a>(lists: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut seen = Vec::new();
+    let mut dances = Vec::new();
+
+    for dance in lists {
+        let dance = dance.trim();
+        let key = dance.to_lowercase();
+
+        if dance.is_empty() || seen.contains(&key) {
+            continue;
+        }
+
+        seen.push(key);
+        dances.push(dance.to_owned());
+    }
+
+    dances
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dances::merge_dance_names;
+
+    #[test]
+    fn merge_keeps_first_spelling() {
+        let merged = merge_dance_names(["Rumba", "", " rumba ", "Jive", "RUMBA", "jive"]);
+
+        assert_eq!(merged, vec!["Rumba", "Jive"]);
+    }
+}
@@ -1,6 +1,7 @@
+use crate::dataloading::dances::{KNOWN_DANCES, merge_dance_names};
 use crate::dataloading::songinfo::SongInfo;
 use crate::traktor_api;
-use crate::traktor_api::TraktorDataProvider;
+use crate::traktor_api::{Playhead, TraktorDataProvider};
 use std::cmp::PartialEq;
 
 #[derive(Default, Debug, PartialEq, Clone)]
@@ -8,7 +9,7 @@ pub enum SongDataSource {
     #[default]
     Blank,
     Traktor,
-    Other(SongInfo),
+    Other(Box<SongInfo>),
     Static(usize),
     Playlist(usize),
 }
@@ -27,6 +28,16 @@ pub enum SongDataEdit {
     Title(String),
     Artist(String),
     Dance(String),
+    Album(String),
+    Key(String),
+    Played(bool),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QueueEdit {
+    MoveUp,
+    MoveDown,
+    Remove,
 }
 
 #[derive(Default)]
@@ -39,7 +50,11 @@ pub struct SongDataProvider {
     pub traktor_provider: TraktorDataProvider,
 
     pub current: SongDataSource,
-    pub next: Option<SongDataSource>,
+    pub queue: Vec<SongDataSource>,
+
+    /// While enabled, song changes are staged in `preview` until they are taken live.
+    pub preview_mode: bool,
+    pub preview: Option<SongDataSource>,
 
     should_scroll: bool,
 }
@@ -48,16 +63,24 @@ impl SongDataProvider {
     pub fn set_vec(&mut self, vec: Vec<SongInfo>) {
         self.playlist_songs = vec;
         self.playlist_played = vec![false; self.playlist_songs.len()];
-
-        if !self.playlist_songs.is_empty() {
-            self.current = SongDataSource::Playlist(0);
-        } else {
-            self.current = SongDataSource::Blank;
+        self.queue
+            .retain(|s| !matches!(s, SongDataSource::Playlist(_)));
+        if matches!(self.preview, Some(SongDataSource::Playlist(_))) {
+            self.preview = None;
         }
+
+        self.current = match self.playlist_songs.iter().position(|s| !s.is_section) {
+            Some(i) => SongDataSource::Playlist(i),
+            None => SongDataSource::Blank,
+        };
     }
 
     pub fn set_statics(&mut self, vec: Vec<SongInfo>) {
         self.statics = vec;
+
+        let statics_len = self.statics.len();
+        self.queue
+            .retain(|s| !matches!(s, SongDataSource::Static(i) if *i >= statics_len));
     }
 
     fn set_current_as_played(&mut self) {
@@ -86,26 +109,132 @@ impl SongDataProvider {
             SongDataSource::Traktor => self.traktor_provider.get_song_info(),
         }
     }
+
+    /// Returns the playhead of the current song while Traktor plays it on air.
+    pub fn get_current_playhead(&self) -> Option<Playhead> {
+        self.traktor_provider
+            .get_playhead_of(self.get_current_song_info()?)
+    }
+
+    pub fn get_queued_song_info<'a>(&'a self, source: &'a SongDataSource) -> Option<&'a SongInfo> {
+        match source {
+            SongDataSource::Static(i) => self.statics.get(*i),
+            SongDataSource::Playlist(i) => self.playlist_songs.get(*i),
+            SongDataSource::Other(song) => Some(song),
+            SongDataSource::Blank => None,
+            SongDataSource::Traktor => self.traktor_provider.get_next_song_info(),
+        }
+    }
+
     pub fn get_next_song_info(&self) -> Option<&SongInfo> {
-        if let Some(next) = self.next.as_ref() {
-            return match next {
-                SongDataSource::Static(i) => self.statics.get(*i),
-                SongDataSource::Playlist(i) => self.playlist_songs.get(*i),
-                SongDataSource::Other(song) => Some(song),
-                SongDataSource::Blank => None,
-                SongDataSource::Traktor => self.traktor_provider.get_next_song_info(),
-            };
+        if let Some(next) = self.queue.first() {
+            return self.get_queued_song_info(next);
         }
 
         match self.current {
             SongDataSource::Static(_) => None,
-            SongDataSource::Playlist(i) => self.playlist_songs.get(i + 1),
+            SongDataSource::Playlist(i) => self
+                .next_song_index(i)
+                .and_then(|i| self.playlist_songs.get(i)),
             SongDataSource::Other(ref song) => Some(song),
             SongDataSource::Blank => None,
             SongDataSource::Traktor => self.traktor_provider.get_next_song_info(),
         }
     }
 
+    /// Returns the dances of up to `count` upcoming songs: the queue first, then
+    /// the playlist after the last queued or current song, or the next song of
+    /// Traktor. Consecutive identical dances are collapsed and songs of another
+    /// playlist section are left out.
+    pub fn get_upcoming_dances(&self, count: usize) -> Vec<&str> {
+        let current_section = self.get_current_section_title();
+        let mut playlist_index = match self.current {
+            SongDataSource::Playlist(i) => Some(i),
+            SongDataSource::Traktor => self.get_current_traktor_index(),
+            _ => None,
+        };
+        let mut songs: Vec<&SongInfo> = Vec::new();
+
+        for source in &self.queue {
+            if let SongDataSource::Playlist(i) = source {
+                if self.get_section_title(*i) != current_section {
+                    continue;
+                }
+                playlist_index = Some(*i);
+            }
+
+            songs.extend(self.get_queued_song_info(source));
+        }
+
+        if self.queue.is_empty()
+            && self.current == SongDataSource::Traktor
+            && let Some(next) = self.traktor_provider.get_next_song_info()
+        {
+            songs.push(next);
+            playlist_index = self
+                .playlist_songs
+                .iter()
+                .position(|s| !s.is_section && TraktorDataProvider::songs_name_match(next, s));
+        }
+
+        // the playlist continues up to the next section header
+        if let Some(index) = playlist_index {
+            songs.extend(
+                self.playlist_songs
+                    .get(index + 1..)
+                    .unwrap_or_default()
+                    .iter()
+                    .take_while(|s| !s.is_section),
+            );
+        }
+
+        let mut dances: Vec<&str> = Vec::with_capacity(count);
+        for dance in songs.iter().map(|s| s.dance.trim()) {
+            if dances.len() == count {
+                break;
+            }
+            if dance.is_empty() || dances.last().is_some_and(|d| d.eq_ignore_ascii_case(dance)) {
+                continue;
+            }
+
+            dances.push(dance);
+        }
+
+        dances
+    }
+
+    /// Returns the index of the first song after `index`, skipping section headers.
+    fn next_song_index(&self, index: usize) -> Option<usize> {
+        (index + 1..self.playlist_songs.len()).find(|&i| !self.playlist_songs[i].is_section)
+    }
+
+    /// Returns the index of the last song before `index`, skipping section headers.
+    fn prev_song_index(&self, index: usize) -> Option<usize> {
+        (0..index.min(self.playlist_songs.len()))
+            .rev()
+            .find(|&i| !self.playlist_songs[i].is_section)
+    }
+
+    /// Returns the title of the section the playlist entry at `index` belongs to.
+    pub fn get_section_title(&self, index: usize) -> Option<&str> {
+        self.playlist_songs
+            .get(..=index)?
+            .iter()
+            .rev()
+            .find(|s| s.is_section)
+            .map(|s| s.title.as_str())
+    }
+
+    pub fn get_current_section_title(&self) -> Option<&str> {
+        match self.current {
+            SongDataSource::Playlist(i) => self.get_section_title(i),
+            SongDataSource::Traktor => self
+                .get_current_traktor_index()
+                .and_then(|i| self.get_section_title(i)),
+            _ => None,
+        }
+    }
+
     pub fn prev(&mut self) {
         self.should_scroll = true;
 
@@ -113,20 +242,20 @@ impl SongDataProvider {
             return;
         };
 
-        if current_index == 0 {
+        let Some(prev_index) = self.prev_song_index(current_index) else {
             return;
-        }
+        };
 
         self.set_current_as_played();
-        self.current = SongDataSource::Playlist(current_index - 1);
+        self.current = SongDataSource::Playlist(prev_index);
     }
 
     pub fn next(&mut self) {
         self.should_scroll = true;
 
-        if let Some(next) = self.next.take() {
+        if !self.queue.is_empty() {
             self.set_current_as_played();
-            self.current = next;
+            self.current = self.queue.remove(0);
             return;
         }
 
@@ -134,12 +263,32 @@ impl SongDataProvider {
             return;
         };
 
-        if current_index == self.playlist_songs.len() - 1 {
+        let Some(next_index) = self.next_song_index(current_index) else {
             return;
-        }
+        };
 
         self.set_current_as_played();
-        self.current = SongDataSource::Playlist(current_index + 1);
+        self.current = SongDataSource::Playlist(next_index);
+    }
+
+    /// Returns the change to the next song of the playlist. From a static or
+    /// a blank song, that is the song after the last one played.
+    pub fn next_playlist_change(&self) -> SongChange {
+        if !self.queue.is_empty()
+            || matches!(
+                self.current,
+                SongDataSource::Playlist(_) | SongDataSource::Traktor
+            )
+        {
+            return SongChange::Next;
+        }
+
+        let index = match self.playlist_played.iter().rposition(|&played| played) {
+            Some(i) => self.next_song_index(i),
+            None => self.playlist_songs.iter().position(|s| !s.is_section),
+        };
+
+        index.map_or(SongChange::Next, SongChange::PlaylistAbsolute)
     }
 
     pub fn set_current(&mut self, n: SongDataSource) {
@@ -152,7 +301,7 @@ impl SongDataProvider {
                 }
             }
             SongDataSource::Playlist(i) => {
-                if self.playlist_songs.get(i).is_some() {
+                if self.playlist_songs.get(i).is_some_and(|s| !s.is_section) {
                     self.current = n;
                 }
             }
@@ -160,8 +309,42 @@ impl SongDataProvider {
         }
     }
 
-    pub fn set_next(&mut self, next: SongDataSource) {
-        self.next = Some(next);
+    pub fn enqueue(&mut self, next: SongDataSource) {
+        if let SongDataSource::Playlist(i) = next
+            && self.playlist_songs.get(i).is_none_or(|s| s.is_section)
+        {
+            return;
+        }
+
+        self.queue.push(next);
+    }
+
+    pub fn handle_queue_edit(&mut self, i: usize, edit: QueueEdit) {
+        if i >= self.queue.len() {
+            return;
+        }
+
+        match edit {
+            QueueEdit::MoveUp => {
+                if i > 0 {
+                    self.queue.swap(i, i - 1);
+                }
+            }
+            QueueEdit::MoveDown => {
+                if i + 1 < self.queue.len() {
+                    self.queue.swap(i, i + 1);
+                }
+            }
+            QueueEdit::Remove => {
+                self.queue.remove(i);
+            }
+        }
+    }
+
+    pub fn get_queue_position(&self, playlist_index: usize) -> Option<usize> {
+        self.queue
+            .iter()
+            .position(|s| *s == SongDataSource::Playlist(playlist_index))
     }
 
     pub fn append_song(&mut self, song: SongInfo) {
@@ -169,12 +352,187 @@ impl SongDataProvider {
         self.playlist_played.push(false);
     }
 
+    /// Inserts songs into the playlist before `index`, shifting the current
+    /// song and queued entries behind it accordingly.
+    pub fn insert_songs(&mut self, index: usize, songs: Vec<SongInfo>) {
+        let index = index.min(self.playlist_songs.len());
+        let count = songs.len();
+
+        self.playlist_played
+            .splice(index..index, std::iter::repeat_n(false, count));
+        self.playlist_songs.splice(index..index, songs);
+
+        for s in self
+            .queue
+            .iter_mut()
+            .chain([&mut self.current])
+            .chain(self.preview.as_mut())
+        {
+            if let SongDataSource::Playlist(j) = s
+                && *j >= index
+            {
+                *j += count;
+            }
+        }
+    }
+
+    /// Reorders the playlist so that the song at `order[k]` ends up at position `k`.
+    /// `order` has to be a permutation of all playlist indices.
+    pub fn reorder_playlist(&mut self, order: &[usize]) {
+        if order.len() != self.playlist_songs.len() {
+            return;
+        }
+
+        let mut new_positions = vec![0; order.len()];
+        for (new_index, &old_index) in order.iter().enumerate() {
+            new_positions[old_index] = new_index;
+        }
+
+        self.playlist_songs = order
+            .iter()
+            .map(|&i| self.playlist_songs[i].clone())
+            .collect();
+        self.playlist_played = order.iter().map(|&i| self.playlist_played[i]).collect();
+
+        for s in self
+            .queue
+            .iter_mut()
+            .chain([&mut self.current])
+            .chain(self.preview.as_mut())
+        {
+            if let SongDataSource::Playlist(j) = s {
+                *j = new_positions[*j];
+            }
+        }
+    }
+
+    /// Deletes a song of the playlist or a static, shifting the current song
+    /// and queued entries behind it accordingly. Deleting the current song
+    /// blanks the display.
     pub fn delete_song(&mut self, song: SongDataSource) {
+        if self.current == song {
+            self.current = SongDataSource::Blank;
+        }
+
         if let SongDataSource::Playlist(i) = song {
             self.playlist_songs.remove(i);
             self.playlist_played.remove(i);
+
+            self.queue.retain(|s| *s != SongDataSource::Playlist(i));
+            if self.preview == Some(SongDataSource::Playlist(i)) {
+                self.preview = None;
+            }
+            for s in self
+                .queue
+                .iter_mut()
+                .chain([&mut self.current])
+                .chain(self.preview.as_mut())
+            {
+                if let SongDataSource::Playlist(j) = s
+                    && *j > i
+                {
+                    *j -= 1;
+                }
+            }
         } else if let SongDataSource::Static(i) = song {
             self.statics.remove(i);
+
+            self.queue.retain(|s| *s != SongDataSource::Static(i));
+            for s in self.queue.iter_mut().chain([&mut self.current]) {
+                if let SongDataSource::Static(j) = s
+                    && *j > i
+                {
+                    *j -= 1;
+                }
+            }
+        }
+    }
+
+    /// Returns the source a song change would switch to. Relative changes
+    /// start from the staged preview if there is one, otherwise from the current song.
+    fn resolve_song_change(&self, change: SongChange) -> Option<SongDataSource> {
+        let base = self.preview.as_ref().unwrap_or(&self.current);
+
+        match change {
+            SongChange::Blank => Some(SongDataSource::Blank),
+            SongChange::Traktor => Some(SongDataSource::Traktor),
+            SongChange::StaticAbsolute(index) => Some(SongDataSource::Static(index)),
+            SongChange::PlaylistAbsolute(index) => self
+                .playlist_songs
+                .get(index)
+                .is_some_and(|s| !s.is_section)
+                .then_some(SongDataSource::Playlist(index)),
+            SongChange::Next => {
+                if self.preview.is_none()
+                    && let Some(next) = self.queue.first()
+                {
+                    return Some(next.clone());
+                }
+
+                match base {
+                    SongDataSource::Playlist(i) => {
+                        self.next_song_index(*i).map(SongDataSource::Playlist)
+                    }
+                    _ => None,
+                }
+            }
+            SongChange::Previous => match base {
+                SongDataSource::Playlist(i) => {
+                    self.prev_song_index(*i).map(SongDataSource::Playlist)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// Stages the result of the song change as preview without changing the current song.
+    pub fn stage_song_change(&mut self, change: SongChange) {
+        if let Some(source) = self.resolve_song_change(change) {
+            self.preview = Some(source);
+        }
+    }
+
+    /// Makes the staged preview the current song. A preview taken from the
+    /// head of the queue is removed from the queue.
+    pub fn take_preview(&mut self) {
+        let Some(preview) = self.preview.take() else {
+            return;
+        };
+
+        if self.queue.first() == Some(&preview) {
+            self.queue.remove(0);
+        }
+
+        self.set_current_as_played();
+        self.current = preview;
+        self.should_scroll = true;
+    }
+
+    pub fn get_preview_song_info(&self) -> Option<&SongInfo> {
+        self.get_queued_song_info(self.preview.as_ref()?)
+    }
+
+    /// Returns the song that would follow the preview once it is taken live.
+    pub fn get_preview_next_song_info(&self) -> Option<&SongInfo> {
+        let preview = self.preview.as_ref()?;
+
+        if let Some(next) = self.queue.iter().find(|&s| s != preview) {
+            return self.get_queued_song_info(next);
+        }
+
+        match preview {
+            SongDataSource::Playlist(i) => self
+                .next_song_index(*i)
+                .and_then(|i| self.playlist_songs.get(i)),
+            SongDataSource::Traktor => self.traktor_provider.get_next_song_info(),
+            _ => None,
+        }
+    }
+
+    pub fn get_preview_section_title(&self) -> Option<&str> {
+        match self.preview.as_ref()? {
+            SongDataSource::Playlist(i) => self.get_section_title(*i),
+            _ => None,
         }
     }
 
@@ -193,6 +551,10 @@ impl SongDataProvider {
                 self.current = SongDataSource::Static(index);
             }
             SongChange::PlaylistAbsolute(index) => {
+                if self.playlist_songs.get(index).is_some_and(|s| s.is_section) {
+                    return;
+                }
+
                 self.set_current_as_played();
                 self.current = SongDataSource::Playlist(index);
             }
@@ -206,6 +568,13 @@ impl SongDataProvider {
     }
 
     pub fn handle_song_data_edit(&mut self, i: usize, edit: SongDataEdit) {
+        if let SongDataEdit::Played(played) = edit {
+            if let Some(v) = self.playlist_played.get_mut(i) {
+                *v = played;
+            }
+            return;
+        }
+
         if let Some(song) = self.playlist_songs.get_mut(i) {
             match edit {
                 SongDataEdit::Title(title) => {
@@ -217,10 +586,28 @@ impl SongDataProvider {
                 SongDataEdit::Dance(dance) => {
                     song.dance = dance;
                 }
+                SongDataEdit::Album(album) => {
+                    song.album = album;
+                }
+                SongDataEdit::Key(key) => {
+                    song.key = key;
+                }
+                SongDataEdit::Played(_) => {}
             }
         }
     }
 
+    /// Returns the dance names to suggest while editing a dance, canonical names first.
+    pub fn get_dance_suggestions(&self) -> Vec<String> {
+        merge_dance_names(
+            KNOWN_DANCES
+                .iter()
+                .copied()
+                .chain(self.statics.iter().map(|s| s.dance.as_str()))
+                .chain(self.playlist_songs.iter().map(|s| s.dance.as_str())),
+        )
+    }
+
     pub fn process_traktor_message(&mut self, message: traktor_api::ServerMessage) {
         self.set_current_as_played();
         self.traktor_provider
@@ -259,11 +646,11 @@ impl SongDataProvider {
 
         if let SongDataSource::Playlist(i) = self.current {
             is_current = playlist_index == i;
-            is_next = playlist_index == (i + 1);
+            is_next = self.queue.is_empty() && Some(playlist_index) == self.next_song_index(i);
         }
 
-        if let Some(SongDataSource::Playlist(i)) = self.next {
-            is_next = playlist_index == i;
+        if let Some(SongDataSource::Playlist(i)) = self.queue.first() {
+            is_next = playlist_index == *i;
         }
 
         if matches!(self.current, SongDataSource::Traktor)
@@ -275,3 +662,181 @@ impl SongDataProvider {
         (is_current, is_next, is_traktor, is_played)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dataprovider::song_data_provider::{
+        QueueEdit, SongChange, SongDataProvider, SongDataSource,
+    };
+    use crate::dataloading::songinfo::SongInfo;
+
+    fn provider_with_songs(n: usize) -> SongDataProvider {
+        let mut provider = SongDataProvider::default();
+        provider.set_vec(
+            (0..n)
+                .map(|i| SongInfo::with_dance(format!("Dance {}", i)))
+                .collect(),
+        );
+        provider.set_statics(vec![SongInfo::with_dance("Pause".to_owned())]);
+        provider
+    }
+
+    #[test]
+    fn upcoming_dances_are_collapsed_and_stop_at_sections() {
+        let mut provider = SongDataProvider::default();
+        provider.set_vec(vec![
+            SongInfo::with_dance("Waltz".to_owned()),
+            SongInfo::with_dance("Tango".to_owned()),
+            SongInfo::with_dance("tango".to_owned()),
+            SongInfo::with_dance("Rumba".to_owned()),
+            SongInfo::section("Dinner".to_owned()),
+            SongInfo::with_dance("Jive".to_owned()),
+        ]);
+
+        assert_eq!(provider.get_upcoming_dances(5), vec!["Tango", "Rumba"]);
+        assert_eq!(provider.get_upcoming_dances(1), vec!["Tango"]);
+
+        provider.enqueue(SongDataSource::Playlist(5));
+        provider.enqueue(SongDataSource::Playlist(3));
+        assert_eq!(provider.get_upcoming_dances(5), vec!["Rumba"]);
+    }
+
+    #[test]
+    fn upcoming_dances_of_a_song_past_the_playlist_are_empty() {
+        let mut provider = provider_with_songs(3);
+        provider.current = SongDataSource::Playlist(5);
+
+        assert!(provider.get_upcoming_dances(1).is_empty());
+    }
+
+    #[test]
+    fn next_consumes_queue_in_order() {
+        let mut provider = provider_with_songs(5);
+        provider.enqueue(SongDataSource::Playlist(3));
+        provider.enqueue(SongDataSource::Static(0));
+
+        assert_eq!(provider.get_next_song_info().unwrap().dance, "Dance 3");
+
+        provider.next();
+        assert_eq!(provider.current, SongDataSource::Playlist(3));
+        assert_eq!(provider.get_next_song_info().unwrap().dance, "Pause");
+
+        provider.next();
+        assert_eq!(provider.current, SongDataSource::Static(0));
+        assert!(provider.queue.is_empty());
+    }
+
+    #[test]
+    fn queue_edits_reorder_and_remove() {
+        let mut provider = provider_with_songs(5);
+        provider.enqueue(SongDataSource::Playlist(1));
+        provider.enqueue(SongDataSource::Playlist(2));
+        provider.enqueue(SongDataSource::Playlist(3));
+
+        provider.handle_queue_edit(2, QueueEdit::MoveUp);
+        provider.handle_queue_edit(0, QueueEdit::MoveDown);
+        provider.handle_queue_edit(2, QueueEdit::Remove);
+
+        assert_eq!(
+            provider.queue,
+            vec![SongDataSource::Playlist(3), SongDataSource::Playlist(1)]
+        );
+    }
+
+    #[test]
+    fn deleting_song_updates_queue_indices() {
+        let mut provider = provider_with_songs(5);
+        provider.enqueue(SongDataSource::Playlist(1));
+        provider.enqueue(SongDataSource::Playlist(4));
+
+        provider.delete_song(SongDataSource::Playlist(1));
+
+        assert_eq!(provider.queue, vec![SongDataSource::Playlist(3)]);
+        assert_eq!(provider.get_queue_position(3), Some(0));
+    }
+
+    #[test]
+    fn deleting_songs_shifts_or_blanks_current() {
+        let mut provider = provider_with_songs(5);
+        provider.current = SongDataSource::Playlist(3);
+
+        provider.delete_song(SongDataSource::Playlist(0));
+        provider.delete_song(SongDataSource::Playlist(1));
+        assert_eq!(provider.current, SongDataSource::Playlist(1));
+        assert_eq!(provider.get_current_song_info().unwrap().dance, "Dance 3");
+
+        provider.delete_song(SongDataSource::Playlist(2));
+        assert_eq!(provider.current, SongDataSource::Playlist(1));
+
+        provider.delete_song(SongDataSource::Playlist(1));
+        assert_eq!(provider.current, SongDataSource::Blank);
+    }
+
+    #[test]
+    fn inserting_songs_shifts_current_and_queue() {
+        let mut provider = provider_with_songs(3);
+        provider.current = SongDataSource::Playlist(2);
+        provider.enqueue(SongDataSource::Playlist(0));
+        provider.enqueue(SongDataSource::Playlist(1));
+
+        provider.insert_songs(1, vec![SongInfo::default(), SongInfo::default()]);
+
+        assert_eq!(provider.playlist_songs.len(), 5);
+        assert_eq!(provider.playlist_played.len(), 5);
+        assert_eq!(provider.current, SongDataSource::Playlist(4));
+        assert_eq!(
+            provider.queue,
+            vec![SongDataSource::Playlist(0), SongDataSource::Playlist(3)]
+        );
+    }
+
+    #[test]
+    fn reordering_playlist_keeps_current_queue_and_played() {
+        let mut provider = provider_with_songs(3);
+        provider.current = SongDataSource::Playlist(0);
+        provider.playlist_played[1] = true;
+        provider.enqueue(SongDataSource::Playlist(2));
+
+        provider.reorder_playlist(&[2, 0, 1]);
+
+        assert_eq!(provider.current, SongDataSource::Playlist(1));
+        assert_eq!(provider.queue, vec![SongDataSource::Playlist(0)]);
+        assert_eq!(provider.playlist_played, vec![false, false, true]);
+    }
+
+    #[test]
+    fn next_and_prev_skip_sections() {
+        let mut provider = SongDataProvider::default();
+        provider.set_vec(vec![
+            SongInfo::section("Standard".to_owned()),
+            SongInfo::with_dance("Tango".to_owned()),
+            SongInfo::section("Latein".to_owned()),
+            SongInfo::with_dance("Rumba".to_owned()),
+        ]);
+        assert_eq!(provider.current, SongDataSource::Playlist(1));
+
+        assert_eq!(provider.get_next_song_info().unwrap().dance, "Rumba");
+        provider.next();
+        assert_eq!(provider.current, SongDataSource::Playlist(3));
+        assert_eq!(provider.get_current_section_title(), Some("Latein"));
+
+        provider.prev();
+        assert_eq!(provider.current, SongDataSource::Playlist(1));
+        assert_eq!(provider.get_current_section_title(), Some("Standard"));
+    }
+
+    #[test]
+    fn staged_song_change_goes_live_on_take() {
+        let mut provider = provider_with_songs(3);
+        provider.enqueue(SongDataSource::Playlist(2));
+
+        provider.stage_song_change(SongChange::Next);
+        assert_eq!(provider.preview, Some(SongDataSource::Playlist(2)));
+        assert_eq!(provider.current, SongDataSource::Playlist(0));
+
+        provider.take_preview();
+        assert_eq!(provider.current, SongDataSource::Playlist(2));
+        assert!(provider.queue.is_empty());
+        assert_eq!(provider.preview, None);
+    }
+}
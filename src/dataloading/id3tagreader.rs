@@ -4,9 +4,10 @@ use id3::Result;
 use id3::frame::PictureType;
 use id3::{Tag, TagLike};
 use std::path::Path;
+use std::time::Duration;
 
 pub fn read_song_info_from_filepath(file: impl AsRef<Path>) -> Result<SongInfo> {
-    let tag = Tag::read_from_path(file)?;
+    let tag = Tag::read_from_path(file.as_ref())?;
 
     let album_art = tag
         .pictures()
@@ -16,14 +17,28 @@ pub fn read_song_info_from_filepath(file: impl AsRef<Path>) -> Result<SongInfo>
         .or(tag.pictures().next())
         .cloned();
 
-    Ok(SongInfo::new(
+    let mut song_info = SongInfo::new(
         tag.track().unwrap_or(0),
         tag.title().unwrap_or("").to_string(),
         tag.artist().unwrap_or("").to_string(),
         tag.genre().unwrap_or("").to_string(),
         album_art.map(|img| Handle::from_bytes(img.data)),
-    ))
+    );
+
+    song_info.album = tag.album().unwrap_or("").to_string();
+    song_info.key = text_frame(&tag, "TKEY").unwrap_or("").to_string();
+    song_info.bpm = text_frame(&tag, "TBPM").and_then(|bpm| bpm.trim().parse().ok());
+    // TLEN is stored in milliseconds
+    song_info.duration = tag.duration().map(|ms| Duration::from_millis(ms as u64));
+    song_info.file_path = Some(file.as_ref().to_path_buf());
+
+    Ok(song_info)
 }
+
+fn text_frame<'a>(tag: &'a Tag, id: &str) -> Option<&'a str> {
+    tag.get(id).and_then(|frame| frame.content().text())
+}
+
 #[allow(dead_code)]
 pub fn read_song_info_from_files(file_list: &[impl AsRef<Path>]) -> Vec<Result<SongInfo>> {
     file_list.iter().map(read_song_info_from_filepath).collect()
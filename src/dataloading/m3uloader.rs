@@ -7,11 +7,27 @@ use crate::dataloading::songinfo::SongInfo;
 use percent_encoding::percent_decode_str;
 use url::Url;
 
+/// Directive marking the start of a playlist section.
+const SECTION_DIRECTIVE: &str = "#EXTGRP:";
+
+enum M3uEntry {
+    File(PathBuf),
+    Section(String),
+}
+
 pub fn load_tag_data_from_m3u(path: &Path) -> Result<Vec<SongInfo>> {
-    let files = load_m3u_content_from_path(path)?;
+    let entries = load_m3u_content_from_path(path)?;
     let mut songtags: Vec<SongInfo> = Vec::new();
 
-    for file in files {
+    for entry in entries {
+        let file = match entry {
+            M3uEntry::File(file) => file,
+            M3uEntry::Section(title) => {
+                songtags.push(SongInfo::section(title));
+                continue;
+            }
+        };
+
         let tag = read_song_info_from_filepath(&file).map_err(|e| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -24,19 +40,84 @@ pub fn load_tag_data_from_m3u(path: &Path) -> Result<Vec<SongInfo>> {
     Ok(songtags)
 }
 
-fn load_m3u_content_from_path(path: &Path) -> Result<Vec<PathBuf>> {
+/// Returns the playlist indices of the songs that cannot be written to an M3U
+/// file because they have no file, e.g. blank songs or songs added by hand.
+pub fn songs_without_file(songs: &[SongInfo]) -> Vec<usize> {
+    songs
+        .iter()
+        .enumerate()
+        .filter(|(_, song)| !song.is_section && song.file_path.is_none())
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Writes the playlist as extended M3U with absolute paths. Section headers are
+/// written as `#EXTGRP` directives. Fails without writing anything if a song
+/// has no file, see [`songs_without_file`].
+pub fn save_playlist_to_m3u(path: &Path, songs: &[SongInfo]) -> Result<()> {
+    let mut content = String::from("#EXTM3U\n");
+    let mut missing = 0;
+
+    for song in songs {
+        if song.is_section {
+            content.push_str(&format!("{}{}\n", SECTION_DIRECTIVE, song.title));
+            continue;
+        }
+
+        let Some(file) = song.file_path.as_ref() else {
+            missing += 1;
+            continue;
+        };
+
+        let seconds = song.duration.map_or(-1, |d| d.as_secs() as i64);
+        content.push_str(&format!(
+            "#EXTINF:{},{} - {}\n{}\n",
+            seconds,
+            song.artist,
+            song.title,
+            file.display()
+        ));
+    }
+
+    if missing > 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} songs have no file", missing),
+        ));
+    }
+
+    std::fs::write(path, content)
+}
+
+fn load_m3u_content_from_path(path: &Path) -> Result<Vec<M3uEntry>> {
     let m3u_content = std::fs::read_to_string(path)?;
     let root = path.parent().unwrap();
 
-    let entries = m3u_content.lines().filter(|x| !x.starts_with('#'));
+    let mut entries = Vec::new();
+    let mut current_section: Option<&str> = None;
 
-    let absolute_paths = entries
-        .map(|entry| parse_file_uri(entry).unwrap_or(parse_encoded_file_name(entry)))
-        .map(|entry| root.join(entry));
+    for line in m3u_content.lines() {
+        if let Some(title) = line.strip_prefix(SECTION_DIRECTIVE) {
+            // some players repeat the group for every entry, so only changes start a section
+            let title = title.trim();
+            if current_section != Some(title) {
+                current_section = Some(title);
+                entries.push(M3uEntry::Section(title.to_owned()));
+            }
+            continue;
+        }
 
-    let accessible_files = absolute_paths.filter(|entry| entry.exists());
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let file = root.join(parse_file_uri(line).unwrap_or(parse_encoded_file_name(line)));
+        if file.exists() {
+            entries.push(M3uEntry::File(file));
+        }
+    }
 
-    Ok(accessible_files.collect::<Vec<PathBuf>>())
+    Ok(entries)
 }
 
 fn parse_file_uri(uri: &str) -> Option<PathBuf> {
@@ -53,7 +134,11 @@ fn parse_encoded_file_name(file: &str) -> PathBuf {
 mod tests {
     use std::path::Path;
 
-    use crate::dataloading::m3uloader::{load_m3u_content_from_path, load_tag_data_from_m3u};
+    use crate::dataloading::m3uloader::{
+        M3uEntry, load_m3u_content_from_path, load_tag_data_from_m3u, save_playlist_to_m3u,
+        songs_without_file,
+    };
+    use crate::dataloading::songinfo::SongInfo;
     use crate::test_file;
 
     #[test]
@@ -75,4 +160,41 @@ mod tests {
         assert_eq!(res.artist, "K7");
         assert_eq!(res.dance, "Test Dance");
     }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn m3u_sections_start_on_group_changes() {
+        let path = std::env::temp_dir().join("danceinterpreter_m3u_sections_test.m3u");
+        std::fs::write(
+            &path,
+            "#EXTM3U\n#EXTGRP:Standard\n/dev/zero\n#EXTGRP:Standard\n/dev/null\n#EXTGRP:Latein\n/dev/zero\n",
+        )
+        .unwrap();
+
+        let entries = load_m3u_content_from_path(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let sections: Vec<&str> = entries
+            .iter()
+            .filter_map(|e| match e {
+                M3uEntry::Section(title) => Some(title.as_str()),
+                M3uEntry::File(_) => None,
+            })
+            .collect();
+        assert_eq!(entries.len(), 5);
+        assert_eq!(sections, vec!["Standard", "Latein"]);
+    }
+
+    #[test]
+    fn m3u_save_refuses_songs_without_file() {
+        let songs = vec![
+            SongInfo::section("Standard".to_owned()),
+            SongInfo::default(),
+        ];
+        assert_eq!(songs_without_file(&songs), vec![1]);
+
+        let path = std::env::temp_dir().join("danceinterpreter_m3u_missing_file_test.m3u");
+        assert!(save_playlist_to_m3u(&path, &songs).is_err());
+        assert!(!path.exists());
+    }
 }
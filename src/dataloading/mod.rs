@@ -1,3 +1,6 @@
+pub mod csvloader;
+pub mod dance_translations;
+pub mod dances;
 pub mod dataprovider;
 pub mod id3tagreader;
 pub mod m3uloader;
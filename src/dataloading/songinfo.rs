@@ -1,4 +1,6 @@
 use iced::widget::image;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct SongInfo {
@@ -7,6 +9,16 @@ pub struct SongInfo {
     pub artist: String,
     pub dance: String,
     pub album_art: Option<image::Handle>,
+
+    pub album: String,
+    pub key: String,
+    pub bpm: Option<f64>,
+    pub duration: Option<Duration>,
+    pub file_path: Option<PathBuf>,
+
+    /// Marks a section header that divides the playlist into blocks.
+    /// Section headers only use `title` and are never played.
+    pub is_section: bool,
 }
 
 impl SongInfo {
@@ -17,6 +29,14 @@ impl SongInfo {
         }
     }
 
+    pub fn section(title: String) -> Self {
+        SongInfo {
+            title,
+            is_section: true,
+            ..Default::default()
+        }
+    }
+
     pub fn new(
         track_number: u32,
         title: String,
@@ -30,6 +50,7 @@ impl SongInfo {
             artist,
             dance,
             album_art,
+            ..Default::default()
         }
     }
 }
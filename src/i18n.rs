@@ -0,0 +1,299 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Languages the texts of the config window and the song windows are available in.
+/// The texts are written in English and double as keys for the other languages.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Language {
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// Language of the system locale, English if that language is not supported.
+    pub fn system() -> Self {
+        static SYSTEM: LazyLock<Language> = LazyLock::new(|| {
+            sys_locale::get_locale().map_or(Language::English, |l| Language::from_locale(&l))
+        });
+
+        *SYSTEM
+    }
+
+    /// Parses locales like `de-DE`, `de_AT.UTF-8` or `en`.
+    fn from_locale(locale: &str) -> Self {
+        let language = locale.split(['-', '_', '.']).next().unwrap_or_default();
+
+        if language.eq_ignore_ascii_case("de") {
+            Language::German
+        } else {
+            Language::English
+        }
+    }
+
+    /// Returns the text in this language, or the English text if there is no translation.
+    pub fn translate(self, text: &'static str) -> &'static str {
+        match self {
+            Language::English => text,
+            Language::German => german(text).unwrap_or(text),
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // languages are always listed in their own language
+        let label = match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// Language chosen in the settings, either a fixed one or the one of the system.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LanguageSetting {
+    #[default]
+    System,
+    Fixed(Language),
+}
+
+impl LanguageSetting {
+    pub const ALL: [LanguageSetting; 3] = [
+        LanguageSetting::System,
+        LanguageSetting::Fixed(Language::English),
+        LanguageSetting::Fixed(Language::German),
+    ];
+
+    pub fn resolve(self) -> Language {
+        match self {
+            LanguageSetting::System => Language::system(),
+            LanguageSetting::Fixed(language) => language,
+        }
+    }
+}
+
+impl Display for LanguageSetting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LanguageSetting::System => write!(f, "{} ({})", tr("System"), Language::system()),
+            LanguageSetting::Fixed(language) => write!(f, "{}", language),
+        }
+    }
+}
+
+static UI_LANGUAGE: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the language of the config window. It is kept globally, as it is
+/// needed by the `Display` implementations shown in pick lists.
+pub fn set_ui_language(language: Language) {
+    let index = Language::ALL
+        .iter()
+        .position(|l| *l == language)
+        .unwrap_or_default();
+    UI_LANGUAGE.store(index, Ordering::Relaxed);
+}
+
+pub fn ui_language() -> Language {
+    Language::ALL[UI_LANGUAGE.load(Ordering::Relaxed)]
+}
+
+/// Translates a text of the config window into the UI language.
+pub fn tr(text: &'static str) -> &'static str {
+    ui_language().translate(text)
+}
+
+fn german(text: &str) -> Option<&'static str> {
+    let translation = match text {
+        // windows and dialogs
+        "Config Window" => "Konfiguration",
+        "Song Window" => "Anzeige",
+        "Select playlist file" => "Playlist-Datei auswählen",
+        "Select folder containing m3u AND audio files (required in flatpak)" => {
+            "Ordner mit m3u- UND Audiodateien auswählen (in Flatpak erforderlich)"
+        }
+        "Save playlist" => "Playlist speichern",
+        "These songs have no file and cannot be saved as M3U, save the playlist as CSV to keep them. Rows:" => {
+            "Diese Songs haben keine Datei und können nicht als M3U gespeichert werden. Speichere die Playlist als CSV, um sie zu behalten. Zeilen:"
+        }
+        "Select template file" => "Vorlagendatei auswählen",
+        "Select background image" => "Hintergrundbild auswählen",
+        "Images" => "Bilder",
+        "Export selected songs" => "Ausgewählte Songs exportieren",
+        "Template" => "Vorlage",
+        "New Section" => "Neuer Block",
+
+        // menus
+        "File" => "Datei",
+        "Edit" => "Bearbeiten",
+        "Columns" => "Spalten",
+        "Colours" => "Farben",
+        "Templates" => "Vorlagen",
+        "SongWindow" => "Anzeige",
+        "Language" => "Sprache",
+        "Interface" => "Oberfläche",
+        "Display" => "Anzeige",
+        "System" => "System",
+        "Open Playlist File" => "Playlist-Datei öffnen",
+        "Save Playlist File" => "Playlist-Datei speichern",
+        "Exit" => "Beenden",
+        "Autoscroll" => "Automatisch scrollen",
+        "Reload Statics" => "Statics neu laden",
+        "Add blank song" => "Leeren Song hinzufügen",
+        "Add section" => "Block hinzufügen",
+        "Fixed Layout" => "Festes Layout",
+        "Open Template File" => "Vorlagendatei öffnen",
+        "Reload Templates" => "Vorlagen neu laden",
+        "The template could not be loaded:" => "Die Vorlage konnte nicht geladen werden:",
+        "Open New Window" => "Neues Fenster öffnen",
+        "Close Window" => "Fenster schließen",
+        "Show Thumbnails" => "Cover anzeigen",
+        "Show Next Dance" => "Nächsten Tanz anzeigen",
+        "Upcoming Dances" => "Kommende Tänze",
+        "Show Block Name" => "Blocknamen anzeigen",
+        "Show Clock" => "Uhr anzeigen",
+        "Show Countdown" => "Countdown anzeigen",
+        "Bilingual Dance Names" => "Tanznamen zweisprachig",
+        "Translations" => "Übersetzungen",
+        "Translation" => "Übersetzung",
+        "Backgrounds" => "Hintergründe",
+        "Default" => "Standard",
+        "Blurred Album Art" => "Unscharfes Cover",
+        "Layout Colour" => "Layout-Farbe",
+        "Gradient" => "Verlauf",
+        "Image" => "Bild",
+        "Choose Image" => "Bild auswählen",
+        "Fullscreen" => "Vollbild",
+        "Borderless" => "Rahmenlos",
+        "Always On Top" => "Immer im Vordergrund",
+        "Transition" => "Übergang",
+        "Duration (ms)" => "Dauer (ms)",
+        "Preview Mode" => "Vorschaumodus",
+        "Show Live Monitor" => "Live-Monitor anzeigen",
+        "Layout Settings" => "Layout-Einstellungen",
+        "Text Outline" => "Textkontur",
+        "Text Shadow" => "Textschatten",
+        "Safe Area (%)" => "Sicherheitsrand (%)",
+        "Rotation" => "Drehung",
+        "Mirror Horizontally" => "Horizontal spiegeln",
+        "Progress" => "Fortschritt",
+        "Off" => "Aus",
+        "Bar" => "Balken",
+        "Remaining Time" => "Restzeit",
+        "Bar and Time" => "Balken und Zeit",
+
+        // countdown
+        "Countdown" => "Countdown",
+        "Clock" => "Uhr",
+        "Label, e.g. Buffet opens in" => "Text, z. B. Buffet öffnet in",
+        "5:00 or @19:30" => "5:00 oder @19:30",
+        "Nothing" => "Nichts",
+        "Next Song" => "Nächster Song",
+        "Start" => "Starten",
+        "Stop" => "Stoppen",
+
+        // monitors and queue
+        "Live" => "Live",
+        "Preview" => "Vorschau",
+        "Nothing staged" => "Nichts vorgemerkt",
+        "Take" => "Übernehmen",
+        "Clear" => "Leeren",
+        "Up Next" => "Als Nächstes",
+        "Blank" => "Leer",
+        "remaining" => "noch",
+
+        // playlist
+        "Section" => "Block",
+        "selected" => "ausgewählt",
+        "Set Dance" => "Tanz setzen",
+        "Clear Played" => "Gespielt zurücksetzen",
+        "Queue" => "Einreihen",
+        "Export" => "Exportieren",
+        "Insert Section" => "Block einfügen",
+        "Search title, artist or dance" => "Titel, Interpret oder Tanz suchen",
+        "All dances" => "Alle Tänze",
+        "Missing dance" => "Ohne Tanz",
+        "Apply order" => "Sortierung übernehmen",
+        "Track" => "Nr.",
+        "Album" => "Album",
+        "Duration" => "Dauer",
+        "Key" => "Tonart",
+        "Dance or category" => "Tanz oder Kategorie",
+
+        // server sidebar
+        "Server Settings" => "Server-Einstellungen",
+        "Enable Server" => "Server aktivieren",
+        "Restart Server" => "Server neu starten",
+        "Server Address: " => "Server-Adresse: ",
+        "Enable Debug Logging" => "Debug-Logging aktivieren",
+        "Sync Mode" => "Sync-Modus",
+        "Next Song Mode" => "Nächster Song",
+        "Next Song Mode (Fallback)" => "Nächster Song (Fallback)",
+
+        // layout editor
+        "Profile" => "Profil",
+        "New profile" => "Neues Profil",
+        "Add" => "Hinzufügen",
+        "Background" => "Hintergrund",
+        "Font name" => "Schriftart",
+        "Size" => "Größe",
+        "Colour" => "Farbe",
+        "Dance" => "Tanz",
+        "Title" => "Titel",
+        "Artist" => "Interpret",
+        "Next Dance" => "Nächster Tanz",
+        "Next Dances" => "Nächste Tänze",
+        "Block Name" => "Blockname",
+        "Sans Serif" => "Serifenlos",
+        "Serif" => "Mit Serifen",
+        "Monospace" => "Monospace",
+        "Custom" => "Eigene",
+        "Light" => "Leicht",
+        "Normal" => "Normal",
+        "Medium" => "Mittel",
+        "Semibold" => "Halbfett",
+        "Bold" => "Fett",
+        "Black" => "Extrafett",
+        "Left" => "Links",
+        "Center" => "Mitte",
+        "Right" => "Rechts",
+        "Top" => "Oben",
+        "Bottom" => "Unten",
+        "Min. Size" => "Min. Größe",
+        "Ellipsis" => "Auslassungspunkte",
+        "Marquee" => "Lauftext",
+
+        // transitions
+        "None" => "Keiner",
+        "Crossfade" => "Überblenden",
+        "Slide" => "Schieben",
+        "Fade Through Black" => "Schwarzblende",
+        _ => return None,
+    };
+
+    Some(translation)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::i18n::Language;
+
+    #[test]
+    fn locales_are_mapped_to_languages() {
+        assert_eq!(Language::from_locale("de-DE"), Language::German);
+        assert_eq!(Language::from_locale("de_AT.UTF-8"), Language::German);
+        assert_eq!(Language::from_locale("en-US"), Language::English);
+        assert_eq!(Language::from_locale("fr"), Language::English);
+    }
+
+    #[test]
+    fn missing_translations_fall_back_to_english() {
+        assert_eq!(Language::German.translate("Next Dance"), "Nächster Tanz");
+        assert_eq!(Language::English.translate("Next Dance"), "Next Dance");
+        assert_eq!(Language::German.translate("Traktor"), "Traktor");
+    }
+}
@@ -1,35 +1,62 @@
 mod async_utils;
 mod dataloading;
+mod i18n;
 mod macros;
+mod settings;
 mod traktor_api;
 mod ui;
 
 use crate::async_utils::run_subscription_with;
+use crate::dataloading::csvloader::{
+    load_songs_from_csv, parse_delimited, save_songs_to_csv, songs_from_delimited,
+    songs_to_delimited,
+};
 use crate::dataloading::dataprovider::song_data_provider::{
-    SongChange, SongDataEdit, SongDataProvider, SongDataSource,
+    QueueEdit, SongChange, SongDataEdit, SongDataProvider, SongDataSource,
 };
 use crate::dataloading::id3tagreader::read_song_info_from_filepath;
-use crate::dataloading::m3uloader::load_tag_data_from_m3u;
+use crate::dataloading::m3uloader::{
+    load_tag_data_from_m3u, save_playlist_to_m3u, songs_without_file,
+};
 use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::{LanguageSetting, set_ui_language, tr};
+use crate::settings::Settings;
 use crate::traktor_api::{
     ServerMessage, StateUpdate, TraktorNextMode, TraktorSyncAction, TraktorSyncMode,
 };
 use crate::ui::config_window::bottombar::BottomBarMessage;
+use crate::ui::config_window::countdown::{CountdownAction, CountdownMessage, CountdownSpec};
+use crate::ui::config_window::dance_backgrounds::DanceBackgroundsMessage;
+use crate::ui::config_window::dance_colors::DanceColorsMessage;
+use crate::ui::config_window::dance_translations::DanceTranslationsMessage;
+use crate::ui::config_window::layout_editor::LayoutEditorMessage;
+use crate::ui::config_window::playlist_columns::{PlaylistColumn, PlaylistColumnsMessage};
+use crate::ui::config_window::playlist_filter::PlaylistFilterMessage;
+use crate::ui::config_window::playlist_grid::PlaylistGridMessage;
+use crate::ui::config_window::playlist_selection::PlaylistSelectionMessage;
 use crate::ui::config_window::sidebar::SidebarMessage;
 use crate::ui::config_window::{ConfigWindow, PLAYLIST_SCROLLABLE_ID};
-use crate::ui::song_window::SongWindow;
+use crate::ui::song_window::progress::ProgressDisplay;
+use crate::ui::song_window::readability::Rotation;
+use crate::ui::song_window::template::SongWindowTemplate;
+use crate::ui::song_window::transition::TransitionKind;
+use crate::ui::song_window::{SongWindow, SongWindowSettings};
+use crate::ui::widget::suggestion_text_input;
 use iced::keyboard::key::Named;
 use iced::keyboard::{Key, Modifiers};
-use iced::widget::operation::{scroll_by, snap_to};
-use iced::widget::scrollable::{AbsoluteOffset, RelativeOffset};
+use iced::widget::operation::{scroll_by, scroll_to, snap_to};
+use iced::widget::scrollable::{AbsoluteOffset, RelativeOffset, Viewport};
 use iced::widget::space::horizontal;
 use iced::window::icon::from_file_data;
-use iced::{Element, Size, Subscription, Task, Theme, exit, keyboard, system, theme, window};
+use iced::{
+    Element, Event, Point, Size, Subscription, Task, Theme, clipboard, event, exit, keyboard,
+    system, theme, time, window,
+};
 use iced_aw::ICED_AW_FONT_BYTES;
-use rfd::FileDialog;
+use rfd::{FileDialog, MessageDialog, MessageLevel};
 use std::env::var;
-use std::path::PathBuf;
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 fn main() -> iced::Result {
     iced::daemon(
@@ -44,6 +71,32 @@ fn main() -> iced::Result {
     .run()
 }
 
+fn is_csv_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"))
+}
+
+/// Tells the user that a template file could not be loaded.
+fn show_template_error(e: &std::io::Error) {
+    MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(tr("Template"))
+        .set_description(format!("{} {}", tr("The template could not be loaded:"), e))
+        .show();
+}
+
+fn load_playlist(path: &Path) -> std::io::Result<Vec<SongInfo>> {
+    if is_csv_file(path) {
+        load_songs_from_csv(path)
+    } else {
+        load_tag_data_from_m3u(path)
+    }
+}
+
+/// How long a display window has to stay in place after moving or resizing
+/// before its placement is saved, so dragging does not write the settings file.
+const PLACEMENT_SAVE_DELAY: Duration = Duration::from_secs(1);
+
 pub trait Window {
     fn new(id: window::Id) -> Self;
 
@@ -55,7 +108,13 @@ pub trait Window {
 
 struct DanceInterpreter {
     config_window: ConfigWindow,
-    song_window: SongWindow,
+    song_windows: Vec<SongWindow>,
+    /// Index of the display window the config window currently edits.
+    selected_song_window: usize,
+    closed_song_windows: Vec<SongWindowSettings>,
+    /// When a display window was last moved or resized, until its placement is saved.
+    placement_changed: Option<Instant>,
+    icon: Option<window::Icon>,
 
     data_provider: SongDataProvider,
 }
@@ -67,31 +126,78 @@ pub enum Message {
     WindowOpened(window::Id),
     WindowResized((window::Id, Size)),
     WindowClosed(window::Id),
+    WindowMoved((window::Id, Point)),
+    SavePlacement,
+    SongWindowMonitor(window::Id, Option<Size>),
+    OpenSongWindow,
+    CloseSongWindow,
+    SelectSongWindow(window::Id),
 
     ThemeChanged(theme::Mode),
 
-    ToggleFullscreen,
-    SetFullscreen(bool),
+    ToggleFullscreen(window::Id),
+    SetFullscreen(window::Id, bool),
+    EnableBorderless(bool),
+    EnableAlwaysOnTop(bool),
+    SetTransition(TransitionKind),
+    SetTransitionDuration(u64),
+    SetNextDanceCount(usize),
+    SetUiLanguage(LanguageSetting),
+    SetDisplayLanguage(LanguageSetting),
+    SetSafeArea(u32),
+    SetRotation(Rotation),
+    SetProgressDisplay(ProgressDisplay),
 
     OpenPlaylist,
+    SavePlaylist,
     ReloadStatics,
     AddSong(SongInfo),
     DeleteSong(SongDataSource),
     ScrollBy(f32),
     SnapTo(RelativeOffset),
+    ScrollTo(AbsoluteOffset),
+    PlaylistScrolled(Viewport),
     AddBlankSong(RelativeOffset),
     Sidebar(SidebarMessage),
     Bottombar(BottomBarMessage),
+    PlaylistFilter(PlaylistFilterMessage),
+    PlaylistColumns(PlaylistColumnsMessage),
+    DanceColors(DanceColorsMessage),
+    DanceTranslations(DanceTranslationsMessage),
+    DanceBackgrounds(DanceBackgroundsMessage),
+    PickBackgroundImage(Option<usize>),
+    Countdown(CountdownMessage),
+    LayoutEditor(LayoutEditorMessage),
+    SelectTemplate(Option<PathBuf>),
+    OpenTemplate,
+    ReloadTemplates,
+    PlaylistSelection(PlaylistSelectionMessage),
+    PlaylistGrid(PlaylistGridMessage),
+    ModifiersChanged(Modifiers),
     Animate,
 
     FileDropped(PathBuf),
     SongChanged(SongChange),
+    TakePreview,
+    ClearPreview,
+    EnablePreviewMode(bool),
     SongDataEdit(usize, SongDataEdit),
-    SetNextSong(SongDataSource),
+    OpenDanceSuggestions(usize),
+    CloseDanceSuggestions,
+    EnqueueSong(SongDataSource),
+    QueueEdit(usize, QueueEdit),
 
     EnableImage(bool),
     EnableNextDance(bool),
+    EnableSection(bool),
+    EnableClock(bool),
+    EnableCountdown(bool),
+    EnableDanceTranslation(bool),
+    EnableTextOutline(bool),
+    EnableTextShadow(bool),
+    EnableMirroring(bool),
     EnableAutoscroll(bool),
+    EnableLiveMonitor(bool),
 
     TraktorMessage(Box<ServerMessage>),
     TraktorSetSyncMode(TraktorSyncMode),
@@ -120,21 +226,20 @@ impl DanceInterpreter {
             icon: icon.clone(),
             ..Default::default()
         });
-        let (song_window, sw_opened) = Self::open_window(window::Settings {
-            platform_specific: Self::get_platform_specific(),
-            icon: icon.clone(),
-            ..Default::default()
-        });
 
-        let state = Self {
+        let mut state = Self {
             config_window,
-            song_window,
+            song_windows: Vec::new(),
+            selected_song_window: 0,
+            closed_song_windows: Vec::new(),
+            placement_changed: None,
+            icon,
 
             data_provider: SongDataProvider::default(),
         };
 
         tasks.push(cw_opened);
-        tasks.push(sw_opened);
+        tasks.push(state.apply_settings(Settings::load()));
         tasks.push(system::theme().map(Message::ThemeChanged));
 
         tasks.push(
@@ -146,6 +251,85 @@ impl DanceInterpreter {
         (state, Task::batch(tasks))
     }
 
+    /// Applies the settings and opens the saved display windows, at least one.
+    fn apply_settings(&mut self, settings: Settings) -> Task<Message> {
+        self.config_window.ui_language = settings.ui_language;
+        set_ui_language(settings.ui_language.resolve());
+        self.config_window.playlist_columns = settings.playlist_columns;
+        self.config_window.dance_colors = settings.dance_colors;
+        self.config_window.dance_translations = settings.dance_translations;
+        self.config_window.dance_backgrounds = settings.dance_backgrounds;
+        self.closed_song_windows = settings.closed_song_windows;
+
+        let mut song_windows = settings.song_windows;
+        if song_windows.is_empty() {
+            song_windows.push(SongWindowSettings::default());
+        }
+
+        Task::batch(
+            song_windows
+                .into_iter()
+                .map(|settings| self.open_song_window(settings)),
+        )
+    }
+
+    fn save_settings(&self) {
+        Settings {
+            ui_language: self.config_window.ui_language,
+            playlist_columns: self.config_window.playlist_columns.clone(),
+            dance_colors: self.config_window.dance_colors.clone(),
+            dance_translations: self.config_window.dance_translations.clone(),
+            dance_backgrounds: self.config_window.dance_backgrounds.clone(),
+            song_windows: self.song_windows.iter().map(SongWindow::settings).collect(),
+            closed_song_windows: self.closed_song_windows.clone(),
+        }
+        .save();
+    }
+
+    fn open_song_window(&mut self, settings: SongWindowSettings) -> Task<Message> {
+        let (mut song_window, opened): (SongWindow, _) =
+            Self::open_window(settings.placement.window_settings(
+                settings.borderless,
+                settings.always_on_top,
+                window::Settings {
+                    platform_specific: Self::get_platform_specific(),
+                    icon: self.icon.clone(),
+                    ..Default::default()
+                },
+            ));
+        song_window.apply_settings(settings);
+        self.song_windows.push(song_window);
+
+        opened
+    }
+
+    fn remove_song_window(&mut self, i: usize) -> SongWindow {
+        let song_window = self.song_windows.remove(i);
+
+        if self.selected_song_window > i || self.selected_song_window >= self.song_windows.len() {
+            self.selected_song_window = self.selected_song_window.saturating_sub(1);
+        }
+
+        song_window
+    }
+
+    fn song_window(&self, window_id: window::Id) -> Option<&SongWindow> {
+        self.song_windows.iter().find(|w| w.id == window_id)
+    }
+
+    fn song_window_mut(&mut self, window_id: window::Id) -> Option<&mut SongWindow> {
+        self.song_windows.iter_mut().find(|w| w.id == window_id)
+    }
+
+    /// Returns the display window the config window currently edits.
+    fn selected_song_window(&self) -> Option<&SongWindow> {
+        self.song_windows.get(self.selected_song_window)
+    }
+
+    fn selected_song_window_mut(&mut self) -> Option<&mut SongWindow> {
+        self.song_windows.get_mut(self.selected_song_window)
+    }
+
     fn open_window<T: Window>(settings: window::Settings) -> (T, Task<Message>) {
         let (id, open) = window::open(settings);
         (T::new(id), open.map(Message::WindowOpened))
@@ -164,9 +348,13 @@ impl DanceInterpreter {
 
     pub fn title(&self, window_id: window::Id) -> String {
         if self.config_window.id == window_id {
-            "Config Window".to_string()
-        } else if self.song_window.id == window_id {
-            "Song Window".to_string()
+            tr("Config Window").to_string()
+        } else if let Some(i) = self.song_windows.iter().position(|w| w.id == window_id) {
+            if self.song_windows.len() > 1 {
+                format!("{} {}", tr("Song Window"), i + 1)
+            } else {
+                tr("Song Window").to_string()
+            }
         } else {
             String::new()
         }
@@ -175,21 +363,136 @@ impl DanceInterpreter {
     pub fn view(&self, window_id: window::Id) -> Element<'_, Message> {
         if self.config_window.id == window_id {
             self.config_window.view(self)
-        } else if self.song_window.id == window_id {
-            self.song_window.view(self)
+        } else if let Some(song_window) = self.song_window(window_id) {
+            song_window.view(self)
         } else {
             horizontal().into()
         }
     }
 
     pub fn update(&mut self, message: Message) -> Task<Message> {
+        let task = self.handle_message(message);
+        self.run_countdown_action();
+        self.observe_song_change();
+        task
+    }
+
+    /// Switches the song as set for the countdown once it reaches zero. The
+    /// switch is taken live directly, even in preview mode.
+    fn run_countdown_action(&mut self) {
+        let Some(action) = self
+            .config_window
+            .countdown
+            .take_expired(chrono::Local::now())
+        else {
+            return;
+        };
+
+        let change = match action {
+            CountdownAction::Nothing => return,
+            CountdownAction::NextSong => self.data_provider.next_playlist_change(),
+            CountdownAction::Static(dance) => {
+                let Some(index) = self
+                    .data_provider
+                    .statics
+                    .iter()
+                    .position(|s| s.dance.eq_ignore_ascii_case(&dance))
+                else {
+                    println!("Failed to find static for countdown: {}", dance);
+                    return;
+                };
+                SongChange::StaticAbsolute(index)
+            }
+        };
+
+        self.data_provider.handle_song_change(change);
+    }
+
+    /// Lets the display windows start a transition if the shown song changed,
+    /// no matter which message changed it.
+    fn observe_song_change(&mut self) {
+        let now = Instant::now();
+        let data_provider = &self.data_provider;
+        let song = data_provider.get_current_song_info();
+        let next = data_provider.get_next_song_info();
+        let section = data_provider.get_current_section_title();
+        let count = self
+            .song_windows
+            .iter()
+            .map(|w| w.next_dance_count)
+            .max()
+            .unwrap_or(1);
+        let upcoming = data_provider.get_upcoming_dances(count);
+
+        self.config_window
+            .countdown
+            .observe(&data_provider.current, chrono::Local::now());
+
+        for song_window in &mut self.song_windows {
+            song_window.transition.observe(
+                &data_provider.current,
+                song,
+                next,
+                &upcoming,
+                section,
+                now,
+            );
+        }
+    }
+
+    fn handle_message(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::WindowOpened(_) => ().into(),
+            Message::WindowOpened(window_id) => {
+                if self.song_window(window_id).is_some() {
+                    window::monitor_size(window_id)
+                        .map(move |size| Message::SongWindowMonitor(window_id, size))
+                } else {
+                    ().into()
+                }
+            }
+            Message::WindowMoved((window_id, position)) => {
+                let Some(song_window) = self.song_window_mut(window_id) else {
+                    return ().into();
+                };
+
+                song_window.on_move(position);
+                self.placement_changed = Some(Instant::now());
+
+                window::monitor_size(window_id)
+                    .map(move |size| Message::SongWindowMonitor(window_id, size))
+            }
+            Message::SongWindowMonitor(window_id, monitor_size) => {
+                let Some(song_window) = self.song_window_mut(window_id) else {
+                    return ().into();
+                };
+
+                if song_window.on_monitor(monitor_size) {
+                    // the saved monitor is not connected, show the window on the primary one
+                    Task::batch([
+                        window::set_mode(window_id, window::Mode::Windowed),
+                        window::move_to(window_id, Point::ORIGIN),
+                    ])
+                } else {
+                    ().into()
+                }
+            }
             Message::WindowResized((window_id, size)) => {
                 if self.config_window.id == window_id {
                     self.config_window.on_resize(size);
-                } else if self.song_window.id == window_id {
-                    self.song_window.on_resize(size);
+                } else if let Some(song_window) = self.song_window_mut(window_id) {
+                    song_window.on_resize(size);
+                    self.placement_changed = Some(Instant::now());
+                }
+
+                ().into()
+            }
+            Message::SavePlacement => {
+                if self
+                    .placement_changed
+                    .is_some_and(|changed| changed.elapsed() >= PLACEMENT_SAVE_DELAY)
+                {
+                    self.placement_changed = None;
+                    self.save_settings();
                 }
 
                 ().into()
@@ -197,24 +500,44 @@ impl DanceInterpreter {
             Message::WindowClosed(window_id) => {
                 if self.config_window.id == window_id {
                     self.config_window.on_close();
-
-                    if self.song_window.is_closed() {
-                        exit()
-                    } else {
-                        window::close(self.song_window.id)
-                    }
-                } else if self.song_window.id == window_id {
-                    self.song_window.on_close();
-
-                    if self.config_window.is_closed() {
-                        exit()
-                    } else {
-                        window::close(self.config_window.id)
-                    }
+                    // keep the placement of the display windows that are still open
+                    self.save_settings();
+                    exit()
+                } else if let Some(i) = self.song_windows.iter().position(|w| w.id == window_id) {
+                    // keep the settings, the window may have been closed by accident
+                    let mut song_window = self.remove_song_window(i);
+                    song_window.on_close();
+                    self.closed_song_windows.push(song_window.settings());
+
+                    self.save_settings();
+                    ().into()
                 } else {
                     ().into()
                 }
             }
+            Message::OpenSongWindow => {
+                let settings = self.closed_song_windows.pop().unwrap_or_default();
+                let opened = self.open_song_window(settings);
+                self.selected_song_window = self.song_windows.len() - 1;
+                self.save_settings();
+                opened
+            }
+            Message::CloseSongWindow => {
+                if self.selected_song_window >= self.song_windows.len() {
+                    return ().into();
+                }
+
+                // removed before the window closes, so its settings are not kept
+                let song_window = self.remove_song_window(self.selected_song_window);
+                self.save_settings();
+                window::close(song_window.id)
+            }
+            Message::SelectSongWindow(window_id) => {
+                if let Some(i) = self.song_windows.iter().position(|w| w.id == window_id) {
+                    self.selected_song_window = i;
+                }
+                ().into()
+            }
 
             Message::ThemeChanged(mode) => {
                 self.config_window.theme = match mode {
@@ -233,26 +556,31 @@ impl DanceInterpreter {
                 );
 
                 if let Ok(icon) = icon {
-                    Task::batch([
-                        window::set_icon(self.song_window.id, icon.clone()),
-                        window::set_icon(self.config_window.id, icon),
-                    ])
+                    self.icon = Some(icon.clone());
+
+                    Task::batch(
+                        self.song_windows
+                            .iter()
+                            .map(|w| w.id)
+                            .chain([self.config_window.id])
+                            .map(|id| window::set_icon(id, icon.clone())),
+                    )
                 } else {
                     ().into()
                 }
             }
 
-            Message::ToggleFullscreen => {
-                let song_window_id = self.song_window.id;
-
-                window::mode(song_window_id)
-                    .map(|mode| Message::SetFullscreen(mode != window::Mode::Fullscreen))
-            }
-            Message::SetFullscreen(fullscreen) => {
-                let song_window_id = self.song_window.id;
+            Message::ToggleFullscreen(window_id) => window::mode(window_id).map(move |mode| {
+                Message::SetFullscreen(window_id, mode != window::Mode::Fullscreen)
+            }),
+            Message::SetFullscreen(window_id, fullscreen) => {
+                if let Some(song_window) = self.song_window_mut(window_id) {
+                    song_window.fullscreen = fullscreen;
+                    self.save_settings();
+                }
 
                 window::set_mode(
-                    song_window_id,
+                    window_id,
                     if fullscreen {
                         window::Mode::Fullscreen
                     } else {
@@ -260,15 +588,100 @@ impl DanceInterpreter {
                     },
                 )
             }
+            Message::EnableBorderless(state) => {
+                let Some(song_window) = self.selected_song_window_mut() else {
+                    return ().into();
+                };
+                if song_window.borderless == state {
+                    return ().into();
+                }
+                song_window.borderless = state;
+                let song_window_id = song_window.id;
+
+                self.save_settings();
+                window::toggle_decorations(song_window_id)
+            }
+            Message::SetTransition(kind) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.transition.settings.kind = kind;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetTransitionDuration(duration_ms) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.transition.settings.duration_ms = duration_ms;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetUiLanguage(language) => {
+                self.config_window.ui_language = language;
+                set_ui_language(language.resolve());
+                self.save_settings();
+                ().into()
+            }
+            Message::SetDisplayLanguage(language) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.language = language;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetSafeArea(safe_area) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.readability.safe_area = safe_area;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetRotation(rotation) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.readability.rotation = rotation;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetProgressDisplay(progress) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.progress = progress;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SetNextDanceCount(count) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.next_dance_count = count;
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::EnableAlwaysOnTop(state) => {
+                let Some(song_window) = self.selected_song_window_mut() else {
+                    return ().into();
+                };
+                song_window.always_on_top = state;
+                let song_window_id = song_window.id;
+
+                self.save_settings();
+                window::set_level(
+                    song_window_id,
+                    if state {
+                        window::Level::AlwaysOnTop
+                    } else {
+                        window::Level::Normal
+                    },
+                )
+            }
 
             Message::OpenPlaylist => {
                 #[cfg(target_os = "linux")]
                 if var("container").is_ok() {
                     // Request folder access first in flatpak environment
                     let folder = FileDialog::new()
-                        .set_title(
+                        .set_title(tr(
                             "Select folder containing m3u AND audio files (required in flatpak)",
-                        )
+                        ))
                         .set_directory(
                             dirs::audio_dir()
                                 .unwrap_or(dirs::home_dir().unwrap_or(PathBuf::from("."))),
@@ -281,9 +694,9 @@ impl DanceInterpreter {
 
                 // Open playlist file
                 let file = FileDialog::new()
-                    .add_filter("Playlist", &["m3u", "m3u8"])
+                    .add_filter("Playlist", &["m3u", "m3u8", "csv"])
                     .add_filter("Any(*)", &["*"])
-                    .set_title("Select playlist file")
+                    .set_title(tr("Select playlist file"))
                     .set_directory(
                         dirs::audio_dir().unwrap_or(dirs::home_dir().unwrap_or(PathBuf::from("."))),
                     )
@@ -294,18 +707,67 @@ impl DanceInterpreter {
                 };
                 println!("Selected file: {:?}", file);
 
-                let Ok(playlist) = load_tag_data_from_m3u(&file) else {
+                let Ok(playlist) = load_playlist(&file) else {
                     return ().into();
                 };
 
                 self.data_provider.set_vec(playlist);
+                self.config_window.playlist_selection.clear();
+                self.config_window.playlist_grid.clear();
+
+                ().into()
+            }
+
+            Message::SavePlaylist => {
+                let file = FileDialog::new()
+                    .add_filter("Playlist", &["m3u", "m3u8"])
+                    .add_filter("CSV", &["csv"])
+                    .set_title(tr("Save playlist"))
+                    .set_file_name("playlist.m3u")
+                    .save_file();
+
+                let Some(file) = file else {
+                    return ().into();
+                };
+
+                let songs = &self.data_provider.playlist_songs;
+                let result = if is_csv_file(&file) {
+                    save_songs_to_csv(&file, songs)
+                } else {
+                    let missing = songs_without_file(songs);
+                    if !missing.is_empty() {
+                        let rows: Vec<String> =
+                            missing.iter().map(|i| (i + 1).to_string()).collect();
+
+                        MessageDialog::new()
+                            .set_level(MessageLevel::Warning)
+                            .set_title(tr("Save playlist"))
+                            .set_description(format!(
+                                "{} {}",
+                                tr("These songs have no file and cannot be saved as M3U, save the playlist as CSV to keep them. Rows:"),
+                                rows.join(", ")
+                            ))
+                            .show();
+
+                        return ().into();
+                    }
+
+                    save_playlist_to_m3u(&file, songs)
+                };
+
+                if let Err(e) = result {
+                    println!("Failed to save playlist: {}", e);
+                }
 
                 ().into()
             }
 
             Message::ReloadStatics => {
                 let file_content = std::fs::read_to_string("./statics.txt");
-                let statics = file_content
+
+                // a static can carry a countdown after its dance, e.g.
+                // `Break | 15:00 | Back in | next`
+                let (statics, countdowns) = file_content
                     .map(|c| {
                         c.trim()
                             .lines()
@@ -313,19 +775,25 @@ impl DanceInterpreter {
                                 let trimmed = l.trim();
                                 (!trimmed.is_empty()).then_some(trimmed)
                             })
-                            .map(|l| SongInfo::with_dance(l.to_owned()))
-                            .collect()
+                            .map(|l| {
+                                let (dance, countdown) = CountdownSpec::parse_static(l);
+                                (SongInfo::with_dance(dance.to_owned()), countdown)
+                            })
+                            .unzip()
                     })
                     .unwrap_or_default();
 
                 self.data_provider.set_statics(statics);
+                self.config_window.countdown.set_attached(countdowns);
 
                 ().into()
             }
 
             Message::FileDropped(path) => {
-                if let Ok(playlist) = load_tag_data_from_m3u(&path) {
+                if let Ok(playlist) = load_playlist(&path) {
                     self.data_provider.set_vec(playlist);
+                    self.config_window.playlist_selection.clear();
+                    self.config_window.playlist_grid.clear();
                 } else if let Ok(song_info) = read_song_info_from_filepath(&path) {
                     self.data_provider.append_song(song_info);
                 }
@@ -334,15 +802,53 @@ impl DanceInterpreter {
             }
 
             Message::SongChanged(song_change) => {
+                // a song changed from the mouse returns the keys to changing songs
+                self.config_window.playlist_grid.unfocus();
+
+                if self.data_provider.preview_mode {
+                    self.data_provider.stage_song_change(song_change);
+                    return ().into();
+                }
+
                 self.data_provider.handle_song_change(song_change);
                 self.try_scroll_to_song()
             }
 
+            Message::TakePreview => {
+                self.config_window.playlist_grid.unfocus();
+                self.data_provider.take_preview();
+                self.try_scroll_to_song()
+            }
+
+            Message::ClearPreview => {
+                self.data_provider.preview = None;
+                ().into()
+            }
+
+            Message::EnablePreviewMode(state) => {
+                self.data_provider.preview_mode = state;
+                self.data_provider.preview = None;
+                ().into()
+            }
+
             Message::SongDataEdit(i, edit) => {
                 self.data_provider.handle_song_data_edit(i, edit);
                 ().into()
             }
 
+            Message::OpenDanceSuggestions(i) => {
+                self.update_dance_suggestions(Some(i));
+                self.handle_playlist_grid_message(PlaylistGridMessage::StartedEditing(
+                    i,
+                    PlaylistColumn::Dance,
+                ))
+            }
+
+            Message::CloseDanceSuggestions => {
+                self.update_dance_suggestions(None);
+                self.handle_playlist_grid_message(PlaylistGridMessage::Commit)
+            }
+
             Message::AddSong(song) => {
                 self.data_provider.append_song(song);
                 ().into()
@@ -354,22 +860,98 @@ impl DanceInterpreter {
             }
 
             Message::DeleteSong(song) => {
+                if matches!(song, SongDataSource::Playlist(_)) {
+                    self.config_window.playlist_selection.clear();
+                }
+
                 self.data_provider.delete_song(song);
                 ().into()
             }
 
-            Message::SetNextSong(i) => {
-                self.data_provider.set_next(i);
+            Message::EnqueueSong(song) => {
+                self.data_provider.enqueue(song);
+                ().into()
+            }
+
+            Message::QueueEdit(i, edit) => {
+                self.data_provider.handle_queue_edit(i, edit);
                 ().into()
             }
 
             Message::EnableImage(state) => {
-                self.song_window.enable_image = state;
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_image = state;
+                    self.save_settings();
+                }
                 ().into()
             }
 
             Message::EnableNextDance(state) => {
-                self.song_window.enable_next_dance = state;
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_next_dance = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableSection(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_section = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableClock(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_clock = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableCountdown(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_countdown = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableDanceTranslation(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.enable_translation = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableTextOutline(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.readability.outline = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableTextShadow(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.readability.shadow = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableMirroring(state) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    song_window.readability.mirrored = state;
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::EnableLiveMonitor(state) => {
+                self.config_window.show_live_monitor = state;
                 ().into()
             }
 
@@ -388,6 +970,13 @@ impl DanceInterpreter {
 
             Message::SnapTo(offset) => snap_to(PLAYLIST_SCROLLABLE_ID.clone(), offset),
 
+            Message::ScrollTo(offset) => scroll_to(PLAYLIST_SCROLLABLE_ID.clone(), offset),
+
+            Message::PlaylistScrolled(viewport) => {
+                self.config_window.playlist_viewport = Some(viewport);
+                ().into()
+            }
+
             Message::Sidebar(msg) => match msg {
                 SidebarMessage::Toggle => {
                     self.config_window
@@ -414,6 +1003,146 @@ impl DanceInterpreter {
                 }
             },
 
+            Message::PlaylistFilter(msg) => {
+                self.config_window.playlist_filter.update(msg);
+                // the rows scrolled to may no longer exist in the filtered playlist
+                Task::done(Message::SnapTo(RelativeOffset::START))
+            }
+
+            Message::PlaylistColumns(PlaylistColumnsMessage::ApplySort) => {
+                let columns = &mut self.config_window.playlist_columns;
+                let mut order: Vec<usize> = (0..self.data_provider.playlist_songs.len()).collect();
+                columns.sort_indices(&mut order, &self.data_provider.playlist_songs);
+                columns.sort = None;
+
+                self.data_provider.reorder_playlist(&order);
+                self.config_window.playlist_selection.clear();
+                self.config_window.playlist_grid.clear();
+                ().into()
+            }
+
+            Message::PlaylistColumns(msg) => {
+                if self.config_window.playlist_columns.update(msg) {
+                    self.save_settings();
+                }
+                ().into()
+            }
+
+            Message::DanceColors(msg) => {
+                if self.config_window.dance_colors.update(msg) {
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::DanceTranslations(msg) => {
+                let config_window = &mut self.config_window;
+                if config_window
+                    .dance_translations_editor
+                    .update(msg, &mut config_window.dance_translations)
+                {
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::DanceBackgrounds(msg) => {
+                if self.config_window.dance_backgrounds.update(msg) {
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::Countdown(msg) => {
+                self.config_window
+                    .countdown
+                    .update(msg, chrono::Local::now());
+                ().into()
+            }
+            Message::PickBackgroundImage(slot) => {
+                let file = FileDialog::new()
+                    .add_filter(tr("Images"), &["png", "jpg", "jpeg", "webp", "bmp"])
+                    .set_title(tr("Select background image"))
+                    .set_directory(
+                        dirs::picture_dir()
+                            .unwrap_or(dirs::home_dir().unwrap_or(PathBuf::from("."))),
+                    )
+                    .pick_file();
+
+                if let Some(file) = file {
+                    self.config_window.background_cache.forget(&file);
+                    self.config_window
+                        .dance_backgrounds
+                        .update(DanceBackgroundsMessage::SetImage(slot, file));
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::LayoutEditor(msg) => {
+                let Some(song_window) = self.song_windows.get_mut(self.selected_song_window) else {
+                    return ().into();
+                };
+
+                if self
+                    .config_window
+                    .layout_editor
+                    .update(msg, &mut song_window.layouts)
+                {
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::SelectTemplate(path) => {
+                if let Some(song_window) = self.selected_song_window_mut() {
+                    if let Err(e) = song_window.set_template(path) {
+                        show_template_error(&e);
+                    }
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::OpenTemplate => {
+                let file = FileDialog::new()
+                    .add_filter(tr("Template"), &["json"])
+                    .set_title(tr("Select template file"))
+                    .set_directory(
+                        SongWindowTemplate::directory()
+                            .filter(|d| d.is_dir())
+                            .unwrap_or(dirs::home_dir().unwrap_or(PathBuf::from("."))),
+                    )
+                    .pick_file();
+
+                if let Some(file) = file
+                    && let Some(song_window) = self.selected_song_window_mut()
+                {
+                    if let Err(e) = song_window.set_template(Some(file)) {
+                        show_template_error(&e);
+                    }
+                    self.save_settings();
+                }
+                ().into()
+            }
+            Message::ReloadTemplates => {
+                self.config_window.available_templates = SongWindowTemplate::list();
+                // pick up changes made to the active template files
+                let mut error = None;
+                for song_window in &mut self.song_windows {
+                    let path = song_window.template_path().cloned();
+                    error = song_window.set_template(path).err().or(error);
+                }
+
+                if let Some(e) = error {
+                    show_template_error(&e);
+                }
+                ().into()
+            }
+
+            Message::PlaylistSelection(msg) => self.handle_playlist_selection_message(msg),
+
+            Message::PlaylistGrid(msg) => self.handle_playlist_grid_message(msg),
+
+            Message::ModifiersChanged(modifiers) => {
+                self.config_window.playlist_selection.modifiers = modifiers;
+                ().into()
+            }
+
             Message::Animate => Task::none(),
 
             Message::TraktorMessage(msg) => {
@@ -479,6 +1208,218 @@ impl DanceInterpreter {
         }
     }
 
+    fn handle_playlist_selection_message(
+        &mut self,
+        msg: PlaylistSelectionMessage,
+    ) -> Task<Message> {
+        let selection = &mut self.config_window.playlist_selection;
+
+        match msg {
+            PlaylistSelectionMessage::Click(i) => {
+                let visible_indices = self
+                    .config_window
+                    .visible_playlist_indices(&self.data_provider);
+                self.config_window
+                    .playlist_selection
+                    .click(i, &visible_indices);
+
+                let column = self.config_window.playlist_grid.cursor.map_or_else(
+                    || {
+                        let columns = self.config_window.playlist_columns.editable();
+                        columns.first().copied().unwrap_or(PlaylistColumn::Title)
+                    },
+                    |(_, column)| column,
+                );
+                self.config_window.playlist_grid.focus(i, column);
+            }
+            PlaylistSelectionMessage::SelectAll => {
+                let visible_indices = self
+                    .config_window
+                    .visible_playlist_indices(&self.data_provider);
+                self.config_window
+                    .playlist_selection
+                    .select_all(&visible_indices);
+            }
+            PlaylistSelectionMessage::Clear => selection.clear(),
+            PlaylistSelectionMessage::SetBulkDance(dance) => selection.bulk_dance = dance,
+            PlaylistSelectionMessage::Edit(edit) => {
+                for i in selection.indices() {
+                    if self
+                        .data_provider
+                        .playlist_songs
+                        .get(i)
+                        .is_some_and(|s| !s.is_section)
+                    {
+                        self.data_provider.handle_song_data_edit(i, edit.clone());
+                    }
+                }
+            }
+            PlaylistSelectionMessage::Delete => {
+                for i in selection.indices().into_iter().rev() {
+                    self.data_provider.delete_song(SongDataSource::Playlist(i));
+                }
+                selection.clear();
+            }
+            PlaylistSelectionMessage::Enqueue => {
+                for i in selection.indices() {
+                    self.data_provider.enqueue(SongDataSource::Playlist(i));
+                }
+            }
+            PlaylistSelectionMessage::Export => {
+                let songs: Vec<SongInfo> = selection
+                    .indices()
+                    .into_iter()
+                    .filter_map(|i| self.data_provider.playlist_songs.get(i).cloned())
+                    .collect();
+
+                let file = FileDialog::new()
+                    .add_filter("CSV", &["csv"])
+                    .set_title(tr("Export selected songs"))
+                    .set_file_name("playlist.csv")
+                    .save_file();
+
+                if let Some(file) = file
+                    && let Err(e) = save_songs_to_csv(&file, &songs)
+                {
+                    println!("Failed to export songs: {}", e);
+                }
+            }
+            PlaylistSelectionMessage::InsertSection => {
+                let index = selection
+                    .indices()
+                    .first()
+                    .copied()
+                    .unwrap_or(self.data_provider.playlist_songs.len());
+                self.data_provider
+                    .insert_songs(index, vec![SongInfo::section(tr("New Section").to_owned())]);
+                selection.clear();
+                selection.select_range(index..index + 1);
+
+                let grid = &mut self.config_window.playlist_grid;
+                grid.focus(index, PlaylistColumn::Title);
+                grid.editing = true;
+            }
+            PlaylistSelectionMessage::Copy => {
+                let songs: Vec<SongInfo> = selection
+                    .indices()
+                    .into_iter()
+                    .filter_map(|i| self.data_provider.playlist_songs.get(i).cloned())
+                    .collect();
+
+                if !songs.is_empty() {
+                    return clipboard::write(songs_to_delimited(&songs, '\t'));
+                }
+            }
+            PlaylistSelectionMessage::Paste => {
+                return clipboard::read()
+                    .map(|c| Message::PlaylistSelection(PlaylistSelectionMessage::Pasted(c)));
+            }
+            PlaylistSelectionMessage::Pasted(Some(content)) => {
+                let selected = selection.indices();
+                let rows = parse_delimited(&content, '\t');
+
+                // a single column without "Artist - Title" lines fills that column of the selection
+                let is_single_column = !rows.is_empty()
+                    && rows
+                        .iter()
+                        .all(|r| r.len() == 1 && !r[0].contains(" - ") && !r[0].starts_with('#'));
+
+                if is_single_column && !selected.is_empty() {
+                    let column = self
+                        .config_window
+                        .playlist_grid
+                        .cursor
+                        .map_or(PlaylistColumn::Dance, |(_, column)| column);
+
+                    for (k, &i) in selected.iter().enumerate() {
+                        let Some(row) = rows.get(if rows.len() == 1 { 0 } else { k }) else {
+                            break;
+                        };
+
+                        if let Some(edit) = column.edit(row[0].trim().to_owned()) {
+                            self.data_provider.handle_song_data_edit(i, edit);
+                        }
+                    }
+                } else {
+                    let songs = songs_from_delimited(&content, '\t');
+                    let count = songs.len();
+                    let index = selected
+                        .first()
+                        .copied()
+                        .unwrap_or(self.data_provider.playlist_songs.len());
+
+                    self.data_provider.insert_songs(index, songs);
+                    selection.select_range(index..index + count);
+                }
+            }
+            PlaylistSelectionMessage::Pasted(None) => {}
+        }
+
+        ().into()
+    }
+
+    fn update_dance_suggestions(&mut self, editing_index: Option<usize>) {
+        self.config_window.dance_suggestions = suggestion_text_input::State::with_selection(
+            self.data_provider.get_dance_suggestions(),
+            editing_index
+                .and_then(|i| self.data_provider.playlist_songs.get(i))
+                .map(|s| &s.dance),
+        );
+    }
+
+    fn handle_playlist_grid_message(&mut self, msg: PlaylistGridMessage) -> Task<Message> {
+        let visible_indices = self
+            .config_window
+            .visible_playlist_indices(&self.data_provider);
+        let columns = self.config_window.playlist_columns.editable();
+        let grid = &mut self.config_window.playlist_grid;
+
+        let new_position = match msg {
+            PlaylistGridMessage::Unfocus => {
+                grid.unfocus();
+                None
+            }
+            PlaylistGridMessage::MoveUp => grid.move_cursor(-1, 0, &visible_indices, &columns),
+            PlaylistGridMessage::MoveDown => grid.move_cursor(1, 0, &visible_indices, &columns),
+            PlaylistGridMessage::MoveLeft => grid.move_cursor(0, -1, &visible_indices, &columns),
+            PlaylistGridMessage::MoveRight => grid.move_cursor(0, 1, &visible_indices, &columns),
+            PlaylistGridMessage::NextCell | PlaylistGridMessage::PreviousCell => {
+                grid.editing = false;
+                grid.step_cursor(
+                    matches!(msg, PlaylistGridMessage::NextCell),
+                    &visible_indices,
+                    &columns,
+                )
+            }
+            PlaylistGridMessage::Edit => {
+                if let Some((i, column)) = grid.cursor
+                    && i < self.data_provider.playlist_songs.len()
+                {
+                    grid.editing = true;
+
+                    if column == PlaylistColumn::Dance {
+                        self.update_dance_suggestions(Some(i));
+                    }
+                }
+                None
+            }
+            PlaylistGridMessage::StartedEditing(i, column) => {
+                grid.focus(i, column);
+                grid.editing = true;
+                None
+            }
+            // the edited cell commits its text itself and drops it on cancel
+            PlaylistGridMessage::Commit | PlaylistGridMessage::Cancel => {
+                grid.editing = false;
+                None
+            }
+        };
+
+        new_position
+            .and_then(|position| self.config_window.playlist_reveal_target(position))
+            .map_or_else(|| ().into(), |offset| Task::done(Message::ScrollTo(offset)))
+    }
+
     fn traktor_provider_force_update(&mut self) -> Task<Message> {
         // send fake state update message to enforce sync refresh
         if let Some(mixer_state) = self
@@ -519,20 +1460,23 @@ impl DanceInterpreter {
 
     fn try_scroll_to_song(&mut self) -> Task<Message> {
         if let Some(index) = self.data_provider.take_scroll_index() {
-            let offset_y =
-                index as f32 / std::cmp::max(1, self.data_provider.playlist_songs.len() - 1) as f32;
-
-            Task::done(Message::SnapTo(RelativeOffset {
-                x: 0.0,
-                y: offset_y,
-            }))
+            let visible_indices = self
+                .config_window
+                .visible_playlist_indices(&self.data_provider);
+            let Some(position) = visible_indices.iter().position(|&i| i == index) else {
+                return ().into();
+            };
+
+            Task::done(Message::ScrollTo(
+                self.config_window.playlist_scroll_target(position),
+            ))
         } else {
             ().into()
         }
     }
 
     fn theme(&self, window_id: window::Id) -> Theme {
-        if self.song_window.id == window_id {
+        if self.song_window(window_id).is_some() {
             Theme::Dark
         } else {
             self.config_window.theme.clone()
@@ -543,32 +1487,81 @@ impl DanceInterpreter {
         let mut subscriptions = vec![
             window::close_events().map(Message::WindowClosed),
             window::resize_events().map(Message::WindowResized),
-            window::events().map(|(_, event)| match event {
+            window::events().map(|(id, event)| match event {
                 window::Event::FileDropped(path) => Message::FileDropped(path),
+                window::Event::Moved(position) => Message::WindowMoved((id, position)),
                 _ => Message::Noop,
             }),
-            keyboard::listen().filter_map(|event| {
-                let keyboard::Event::KeyPressed { key, .. } = event else {
-                    return None;
-                };
-
-                match key {
-                    Key::Named(Named::ArrowRight) | Key::Named(Named::Space) => {
-                        Some(Message::SongChanged(SongChange::Next))
-                    }
-                    Key::Named(Named::ArrowLeft) => {
-                        Some(Message::SongChanged(SongChange::Previous))
-                    }
-                    Key::Named(Named::End) => {
-                        Some(Message::SongChanged(SongChange::StaticAbsolute(0)))
-                    }
-                    Key::Named(Named::F11) => Some(Message::ToggleFullscreen),
-                    Key::Named(Named::F5) => Some(Message::ReloadStatics),
-                    Key::Named(Named::PageUp) => Some(Message::ScrollBy(-10.0)),
-                    Key::Named(Named::PageDown) => Some(Message::ScrollBy(10.0)),
-                    _ => None,
+            keyboard::listen().filter_map(|event| match event {
+                keyboard::Event::ModifiersChanged(modifiers) => {
+                    Some(Message::ModifiersChanged(modifiers))
                 }
+                _ => None,
             }),
+            // F11 toggles the window that received it, so it needs the window id
+            event::listen_with(|event, status, window_id| match (event, status) {
+                (
+                    Event::Keyboard(keyboard::Event::KeyPressed {
+                        key: Key::Named(Named::F11),
+                        ..
+                    }),
+                    event::Status::Ignored,
+                ) => Some(Message::ToggleFullscreen(window_id)),
+                _ => None,
+            }),
+            // song change keys are only active while the playlist grid is not focused
+            if self.config_window.playlist_grid.focused {
+                keyboard::listen().filter_map(|event| {
+                    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+                        return None;
+                    };
+
+                    let grid_message = match key {
+                        Key::Named(Named::ArrowUp) => PlaylistGridMessage::MoveUp,
+                        Key::Named(Named::ArrowDown) => PlaylistGridMessage::MoveDown,
+                        Key::Named(Named::ArrowLeft) => PlaylistGridMessage::MoveLeft,
+                        Key::Named(Named::ArrowRight) => PlaylistGridMessage::MoveRight,
+                        Key::Named(Named::Tab) if modifiers.shift() => {
+                            PlaylistGridMessage::PreviousCell
+                        }
+                        Key::Named(Named::Tab) => PlaylistGridMessage::NextCell,
+                        Key::Named(Named::Enter) => PlaylistGridMessage::Edit,
+                        Key::Named(Named::Escape) => PlaylistGridMessage::Unfocus,
+                        Key::Named(Named::F5) => return Some(Message::ReloadStatics),
+                        Key::Named(Named::PageUp) => return Some(Message::ScrollBy(-10.0)),
+                        Key::Named(Named::PageDown) => return Some(Message::ScrollBy(10.0)),
+                        _ => return None,
+                    };
+
+                    Some(Message::PlaylistGrid(grid_message))
+                })
+            } else {
+                keyboard::listen().filter_map(|event| {
+                    let keyboard::Event::KeyPressed { key, .. } = event else {
+                        return None;
+                    };
+
+                    match key {
+                        Key::Named(Named::ArrowRight) | Key::Named(Named::Space) => {
+                            Some(Message::SongChanged(SongChange::Next))
+                        }
+                        Key::Named(Named::ArrowLeft) => {
+                            Some(Message::SongChanged(SongChange::Previous))
+                        }
+                        Key::Named(Named::End) => {
+                            Some(Message::SongChanged(SongChange::StaticAbsolute(0)))
+                        }
+                        Key::Named(Named::Enter) => Some(Message::TakePreview),
+                        Key::Named(Named::F5) => Some(Message::ReloadStatics),
+                        Key::Named(Named::PageUp) => Some(Message::ScrollBy(-10.0)),
+                        Key::Named(Named::PageDown) => Some(Message::ScrollBy(10.0)),
+                        Key::Named(Named::Escape) => {
+                            Some(Message::PlaylistSelection(PlaylistSelectionMessage::Clear))
+                        }
+                        _ => None,
+                    }
+                })
+            },
             keyboard::listen().filter_map(|event| {
                 let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
                     return None;
@@ -578,6 +1571,15 @@ impl DanceInterpreter {
                         Some(Message::AddBlankSong(RelativeOffset::END))
                     }
                     (Key::Character("r"), Modifiers::CTRL) => Some(Message::ReloadStatics),
+                    (Key::Character("a"), Modifiers::CTRL) => Some(Message::PlaylistSelection(
+                        PlaylistSelectionMessage::SelectAll,
+                    )),
+                    (Key::Character("c"), Modifiers::CTRL) => {
+                        Some(Message::PlaylistSelection(PlaylistSelectionMessage::Copy))
+                    }
+                    (Key::Character("v"), Modifiers::CTRL) => {
+                        Some(Message::PlaylistSelection(PlaylistSelectionMessage::Paste))
+                    }
                     (Key::Character("c"), Modifiers::ALT) => {
                         Some(Message::Sidebar(SidebarMessage::Toggle))
                     }
@@ -598,11 +1600,43 @@ impl DanceInterpreter {
                     .bottombar
                     .state
                     .is_animating(Instant::now())
+                || self
+                    .song_windows
+                    .iter()
+                    .any(|w| w.transition.is_animating(Instant::now()))
             {
                 window::frames().map(|_| Message::Animate)
             } else {
                 Subscription::none()
             },
+            if self.config_window.countdown.running.is_some()
+                || self.song_windows.iter().any(|w| {
+                    w.enable_clock
+                        || w.template
+                            .as_ref()
+                            .is_some_and(|template| template.uses_clock())
+                })
+            {
+                time::every(Duration::from_secs(1)).map(|_| Message::Animate)
+            } else {
+                Subscription::none()
+            },
+            if self.placement_changed.is_some() {
+                time::every(PLACEMENT_SAVE_DELAY / 2).map(|_| Message::SavePlacement)
+            } else {
+                Subscription::none()
+            },
+            // the playhead is extrapolated while drawing, so the bar only has
+            // to be redrawn often enough to move smoothly
+            if self
+                .data_provider
+                .get_current_playhead()
+                .is_some_and(|p| p.is_playing())
+            {
+                time::every(Duration::from_millis(100)).map(|_| Message::Animate)
+            } else {
+                Subscription::none()
+            },
         ];
 
         if let Some(addr) = self.data_provider.traktor_provider.get_socket_addr() {
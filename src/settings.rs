@@ -0,0 +1,64 @@
+use crate::dataloading::dance_translations::DanceTranslations;
+use crate::i18n::LanguageSetting;
+use crate::ui::config_window::dance_backgrounds::DanceBackgrounds;
+use crate::ui::config_window::dance_colors::DanceColors;
+use crate::ui::config_window::playlist_columns::PlaylistColumns;
+use crate::ui::song_window::SongWindowSettings;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// User settings that are kept across restarts.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub ui_language: LanguageSetting,
+    pub playlist_columns: PlaylistColumns,
+    pub dance_colors: DanceColors,
+    pub dance_translations: DanceTranslations,
+    pub dance_backgrounds: DanceBackgrounds,
+    /// One entry per display window, opened again at startup.
+    pub song_windows: Vec<SongWindowSettings>,
+    /// Display windows closed with the window button, most recent last. They
+    /// are opened again by "Open New Window" until removed with "Close Window".
+    pub closed_song_windows: Vec<SongWindowSettings>,
+}
+
+impl Settings {
+    fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("danceinterpreter").join("settings.json"))
+    }
+
+    /// Loads the settings file, falling back to the defaults if it is missing or invalid.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        serde_json::from_str(&content).unwrap_or_else(|e| {
+            println!("Failed to parse settings file {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) {
+        let Some(path) = Self::path() else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                let content = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+                std::fs::write(&path, content)
+            });
+
+        if let Err(e) = result {
+            println!("Failed to save settings file {}: {}", path.display(), e);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::dataloading::songinfo::SongInfo;
 use crate::traktor_api::{
-    AppMessage, ChannelState, DeckContentState, DeckState, MixerState, ServerMessage, State,
-    StateUpdate,
+    AppMessage, ChannelState, DeckContentState, DeckPlayState, DeckState, MixerState,
+    ServerMessage, State, StateUpdate,
 };
 use iced::futures::channel::mpsc::UnboundedSender;
 use iced::widget::image;
@@ -9,6 +9,8 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::mem;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub const TRAKTOR_SERVER_DEFAULT_ADDR: &str = "127.0.0.1:8080";
 
@@ -53,6 +55,49 @@ impl Display for TraktorSyncAction {
     }
 }
 
+/// Playhead of the deck on air.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Playhead {
+    /// Seconds since the start of the track.
+    pub position: f64,
+    /// Length of the track in seconds.
+    pub length: f64,
+    /// Playback rate including the pitch, zero while the deck is paused.
+    pub speed: f64,
+}
+
+impl Playhead {
+    /// Extrapolates the last play state of a deck to the given Traktor time in milliseconds.
+    pub fn extrapolate(play_state: &DeckPlayState, length: f64, timestamp_ms: i64) -> Self {
+        Self {
+            position: play_state.position_at(timestamp_ms).clamp(0.0, length),
+            length,
+            speed: play_state.speed,
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.speed != 0.0
+    }
+
+    /// Played part of the track, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        if self.length > 0.0 {
+            (self.position / self.length).clamp(0.0, 1.0) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// Time until the end of the track at the current speed. While the deck
+    /// is paused or played backwards, the time left at normal speed.
+    pub fn remaining(&self) -> Duration {
+        let left = (self.length - self.position).max(0.0);
+        let speed = if self.speed > 0.0 { self.speed } else { 1.0 };
+        Duration::from_secs_f64(left / speed)
+    }
+}
+
 pub struct TraktorDataProvider {
     pub is_enabled: bool,
     pub address: String,
@@ -67,6 +112,8 @@ pub struct TraktorDataProvider {
     time_offset_ms: i64,
     pub state: Option<State>,
     covers: HashMap<String, image::Handle>,
+    /// Deck that was on air last, kept while it is paused.
+    on_air_deck: Option<usize>,
 
     sync_x_fader_is_left: bool,
 
@@ -94,6 +141,7 @@ impl Default for TraktorDataProvider {
             time_offset_ms: 0,
             state: None,
             covers: HashMap::new(),
+            on_air_deck: None,
 
             sync_x_fader_is_left: true,
 
@@ -126,6 +174,7 @@ impl TraktorDataProvider {
     pub fn reconnect(&mut self) {
         self.time_offset_ms = 0;
         self.state = None;
+        self.on_air_deck = None;
         self.sync_x_fader_is_left = true;
         self.update_song_info(&[]);
 
@@ -162,6 +211,36 @@ impl TraktorDataProvider {
         self.cached_next_song_info.as_ref()
     }
 
+    /// Returns the playhead of the deck on air, if that deck plays the song.
+    /// The position is extrapolated from the last update, so it keeps moving
+    /// between the updates of Traktor.
+    pub fn get_playhead_of(&self, song: &SongInfo) -> Option<Playhead> {
+        if !self.is_ready() {
+            return None;
+        }
+
+        let deck = &self.state.as_ref()?.decks[self.on_air_deck?];
+        let content = &deck.content;
+        if !content.is_loaded
+            || content.track_length <= 0.0
+            || content.title != song.title
+            || content.artist != song.artist
+        {
+            return None;
+        }
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .ok()?;
+
+        Some(Playhead::extrapolate(
+            &deck.play_state,
+            content.track_length,
+            now_ms + self.time_offset_ms,
+        ))
+    }
+
     fn get_deck_score(&self, deck: &DeckState, channel: &ChannelState, mixer: &MixerState) -> f64 {
         if !deck.content.is_loaded || deck.play_state.speed == 0.0 || channel.volume == 0.0 {
             return 0.0;
@@ -204,6 +283,7 @@ impl TraktorDataProvider {
         } else {
             return;
         };
+        self.on_air_deck = Some(max_index);
 
         let content = &state.decks[max_index].content;
         let channel = &state.channels[max_index];
@@ -295,18 +375,18 @@ impl TraktorDataProvider {
                 deck.map(|d| self.copy_song_info_from_deck(&d.content, playlist))
             }
             TraktorNextMode::PlaylistByNumber => {
-                let current_index = playlist
-                    .iter()
-                    .position(|s| current_song_info.track_number == s.track_number);
+                let current_index = playlist.iter().position(|s| {
+                    !s.is_section && current_song_info.track_number == s.track_number
+                });
 
-                current_index.and_then(|ci| playlist.get(ci + 1).cloned())
+                current_index.and_then(|ci| Self::next_playlist_song(playlist, ci))
             }
             TraktorNextMode::PlaylistByName => {
                 let current_index = playlist
                     .iter()
-                    .position(|s| Self::songs_name_match(current_song_info, s));
+                    .position(|s| !s.is_section && Self::songs_name_match(current_song_info, s));
 
-                current_index.and_then(|ci| playlist.get(ci + 1).cloned())
+                current_index.and_then(|ci| Self::next_playlist_song(playlist, ci))
             }
             TraktorNextMode::None => None,
         }
@@ -325,6 +405,14 @@ impl TraktorDataProvider {
             self.covers.get(&content.file_path).cloned(),
         );
 
+        song_info.album = content.album.to_owned();
+        song_info.key = content.key.to_owned();
+        song_info.bpm = (content.bpm > 0.0).then_some(content.bpm);
+        song_info.duration =
+            (content.track_length > 0.0).then(|| Duration::from_secs_f64(content.track_length));
+        song_info.file_path =
+            (!content.file_path.is_empty()).then(|| PathBuf::from(&content.file_path));
+
         if song_info.album_art.is_none() {
             song_info.album_art = playlist
                 .iter()
@@ -335,6 +423,13 @@ impl TraktorDataProvider {
         song_info
     }
 
+    fn next_playlist_song(playlist: &[SongInfo], index: usize) -> Option<SongInfo> {
+        playlist[index + 1..]
+            .iter()
+            .find(|s| !s.is_section)
+            .cloned()
+    }
+
     pub fn songs_name_match(a: &SongInfo, b: &SongInfo) -> bool {
         // TODO: maybe change this to levenshtein or sth
         a.artist == b.artist && a.title == b.title
@@ -364,6 +459,7 @@ impl TraktorDataProvider {
 
                 self.time_offset_ms = 0;
                 self.state = None;
+                self.on_air_deck = None;
                 self.sync_x_fader_is_left = true;
                 self.update_song_info(playlist);
 
@@ -452,7 +548,7 @@ impl TraktorDataProvider {
         playlist
             .iter()
             .enumerate()
-            .find(|(_i, s)| TraktorDataProvider::songs_name_match(s, traktor_song))
+            .find(|(_i, s)| !s.is_section && TraktorDataProvider::songs_name_match(s, traktor_song))
             .map(|(i, _s)| i)
     }
 
@@ -464,3 +560,36 @@ impl TraktorDataProvider {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::traktor_api::{DeckPlayState, Playhead};
+    use std::time::Duration;
+
+    #[test]
+    fn playhead_moves_at_the_speed_of_the_deck() {
+        let play_state = DeckPlayState {
+            timestamp: 10_000,
+            position: 60.0,
+            speed: 1.5,
+        };
+
+        let playhead = Playhead::extrapolate(&play_state, 180.0, 12_000);
+        assert_eq!(playhead.position, 63.0);
+        assert_eq!(playhead.remaining(), Duration::from_secs(78));
+
+        let end = Playhead::extrapolate(&play_state, 180.0, 100_000);
+        assert_eq!(end.progress(), 1.0);
+
+        let paused = Playhead::extrapolate(
+            &DeckPlayState {
+                speed: 0.0,
+                ..play_state
+            },
+            180.0,
+            100_000,
+        );
+        assert_eq!(paused.position, 60.0);
+        assert_eq!(paused.remaining(), Duration::from_secs(120));
+    }
+}
@@ -166,6 +166,15 @@ pub struct DeckPlayState {
     pub speed: f64,
 }
 
+impl DeckPlayState {
+    /// Extrapolates the position in seconds to the given Traktor time in
+    /// milliseconds, assuming the deck kept its speed since the update.
+    pub fn position_at(&self, timestamp_ms: i64) -> f64 {
+        let elapsed_ms = (timestamp_ms - self.timestamp as i64).max(0);
+        self.position + self.speed * elapsed_ms as f64 / 1000.0
+    }
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(in crate::traktor_api) struct ConnectionResponse {
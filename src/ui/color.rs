@@ -0,0 +1,65 @@
+use iced::Color;
+use serde::{Deserialize, Serialize};
+
+/// Colour edited as `#rrggbb` text. Text that is not a valid colour yet is
+/// kept as draft, so it can be typed character by character.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[u8; 3]", into = "[u8; 3]")]
+pub struct HexColor {
+    rgb: [u8; 3],
+    draft: Option<String>,
+}
+
+impl HexColor {
+    pub const fn new(rgb: [u8; 3]) -> Self {
+        Self { rgb, draft: None }
+    }
+
+    pub fn color(&self) -> Color {
+        Color::from_rgb8(self.rgb[0], self.rgb[1], self.rgb[2])
+    }
+
+    /// Returns the draft while one is being typed, otherwise the colour as hex.
+    pub fn hex(&self) -> String {
+        self.draft.clone().unwrap_or_else(|| {
+            format!("#{:02x}{:02x}{:02x}", self.rgb[0], self.rgb[1], self.rgb[2])
+        })
+    }
+
+    /// Sets the colour from hex text. Returns whether the text was a valid colour.
+    pub fn set_hex(&mut self, hex: String) -> bool {
+        match parse_hex(&hex) {
+            Some(rgb) => {
+                self.rgb = rgb;
+                self.draft = None;
+                true
+            }
+            None => {
+                self.draft = Some(hex);
+                false
+            }
+        }
+    }
+}
+
+impl From<[u8; 3]> for HexColor {
+    fn from(rgb: [u8; 3]) -> Self {
+        Self::new(rgb)
+    }
+}
+
+impl From<HexColor> for [u8; 3] {
+    fn from(color: HexColor) -> Self {
+        color.rgb
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+    let hex = hex.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
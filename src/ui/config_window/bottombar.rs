@@ -1,8 +1,10 @@
-use crate::dataloading::dataprovider::song_data_provider::SongChange;
+use crate::dataloading::dataprovider::song_data_provider::{SongChange, SongDataSource};
+use crate::i18n::tr;
+use crate::ui::config_window::dance_colors::colored_button_style;
 use crate::{DanceInterpreter, Message};
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::scrollable::{Direction, Scrollbar};
-use iced::widget::{Button, Container, button, container, row, scrollable, text};
+use iced::widget::{Button, Container, button, container, mouse_area, row, scrollable, text};
 use iced::{Animation, Element, Font, Length, Theme, animation, font};
 use std::time::Duration;
 
@@ -56,7 +58,7 @@ impl Bottombar {
         };
 
         let btn_blank: Button<Message> =
-            button(text("Blank").align_y(Vertical::Center).font(bold_font))
+            button(text(tr("Blank")).align_y(Vertical::Center).font(bold_font))
                 .style(button::secondary)
                 .on_press(Message::SongChanged(SongChange::Blank));
         let btn_traktor: Button<Message> =
@@ -69,9 +71,20 @@ impl Bottombar {
             .iter()
             .enumerate()
             .map(|(idx, s)| {
-                button(text(&s.dance).font(bold_font))
+                let mut btn = button(text(&s.dance).font(bold_font))
                     .style(button::secondary)
-                    .on_press(Message::SongChanged(SongChange::StaticAbsolute(idx)))
+                    .on_press(Message::SongChanged(SongChange::StaticAbsolute(idx)));
+
+                if let Some(color) = dance_interpreter
+                    .config_window
+                    .dance_colors
+                    .color_for(&s.dance)
+                {
+                    btn = btn.style(colored_button_style(color));
+                }
+
+                mouse_area(btn)
+                    .on_right_press(Message::EnqueueSong(SongDataSource::Static(idx)))
                     .into()
             })
             .collect();
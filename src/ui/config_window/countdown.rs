@@ -0,0 +1,352 @@
+use crate::Message;
+use crate::dataloading::dataprovider::song_data_provider::SongDataSource;
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::tr;
+use crate::ui::config_window::label_message_button_shrink;
+use crate::ui::song_window::progress::format_remaining;
+use chrono::{DateTime, Local, NaiveTime, TimeDelta};
+use iced::alignment::Vertical;
+use iced::widget::{pick_list, row, text, text_input};
+use iced::{Element, Length};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// When a countdown reaches zero: after a duration from its start, or at a time of day.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CountdownEnd {
+    After(Duration),
+    At(NaiveTime),
+}
+
+impl CountdownEnd {
+    /// Parses a duration as `m:ss` or whole minutes, or a time of day as `@hh:mm`.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+
+        if let Some(time) = text.strip_prefix('@') {
+            return NaiveTime::parse_from_str(time.trim(), "%H:%M")
+                .ok()
+                .map(CountdownEnd::At);
+        }
+
+        let seconds = match text.split_once(':') {
+            Some((minutes, seconds)) => {
+                let seconds = seconds.parse::<u64>().ok().filter(|s| *s < 60)?;
+                minutes.parse::<u64>().ok()? * 60 + seconds
+            }
+            None => text.parse::<u64>().ok()? * 60,
+        };
+
+        (seconds > 0).then(|| CountdownEnd::After(Duration::from_secs(seconds)))
+    }
+
+    /// Returns when a countdown started now ends. A time of day that has
+    /// already passed today is taken as tomorrow.
+    fn deadline(&self, now: DateTime<Local>) -> DateTime<Local> {
+        match self {
+            CountdownEnd::After(duration) => {
+                now + TimeDelta::from_std(*duration).unwrap_or(TimeDelta::zero())
+            }
+            CountdownEnd::At(time) => {
+                let today = now
+                    .date_naive()
+                    .and_time(*time)
+                    .and_local_timezone(Local)
+                    .earliest()
+                    .unwrap_or(now);
+
+                if today <= now {
+                    today + TimeDelta::days(1)
+                } else {
+                    today
+                }
+            }
+        }
+    }
+}
+
+/// What happens when a countdown reaches zero.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub enum CountdownAction {
+    #[default]
+    Nothing,
+    /// Switches to the next song of the playlist, e.g. at the end of a break.
+    NextSong,
+    /// Switches to the static with this dance.
+    Static(String),
+}
+
+impl CountdownAction {
+    /// Parses `next` or the dance of a static, anything else does nothing.
+    fn parse(text: &str) -> Self {
+        match text.trim() {
+            "" => CountdownAction::Nothing,
+            action if action.eq_ignore_ascii_case("next") => CountdownAction::NextSong,
+            dance => CountdownAction::Static(dance.to_owned()),
+        }
+    }
+}
+
+impl Display for CountdownAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CountdownAction::Nothing => f.write_str(tr("Nothing")),
+            CountdownAction::NextSong => f.write_str(tr("Next Song")),
+            CountdownAction::Static(dance) => write!(f, "{}", dance),
+        }
+    }
+}
+
+/// A countdown attached to a static, started whenever the static is shown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CountdownSpec {
+    pub label: String,
+    pub end: CountdownEnd,
+    pub action: CountdownAction,
+}
+
+impl CountdownSpec {
+    /// Parses the part of a line of the statics file after the dance:
+    /// `end | label | action`, e.g. `@19:30 | Buffet opens in | next`.
+    /// The label and the action are optional.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut fields = text.split('|');
+        let end = CountdownEnd::parse(fields.next()?)?;
+
+        Some(Self {
+            label: fields.next().unwrap_or_default().trim().to_owned(),
+            end,
+            action: CountdownAction::parse(fields.next().unwrap_or_default()),
+        })
+    }
+
+    /// Splits a line of the statics file into the dance and its countdown.
+    /// Lines without a valid countdown after the first `|` are taken as a
+    /// dance as a whole, so dances may contain `|` themselves.
+    pub fn parse_static(line: &str) -> (&str, Option<Self>) {
+        let line = line.trim();
+
+        line.split_once('|')
+            .and_then(|(dance, countdown)| Some((dance.trim(), Some(Self::parse(countdown)?))))
+            .unwrap_or((line, None))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RunningCountdown {
+    pub label: String,
+    deadline: DateTime<Local>,
+    action: CountdownAction,
+    /// Index of the static that started the countdown. Such countdowns stop
+    /// when another song is shown.
+    static_index: Option<usize>,
+}
+
+impl RunningCountdown {
+    pub fn remaining(&self, now: DateTime<Local>) -> Duration {
+        (self.deadline - now).to_std().unwrap_or_default()
+    }
+
+    /// Returns the label followed by the remaining time.
+    pub fn text(&self, now: DateTime<Local>) -> String {
+        let remaining = format_remaining(self.remaining(now));
+
+        if self.label.is_empty() {
+            remaining
+        } else {
+            format!("{} {}", self.label, remaining)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CountdownMessage {
+    SetLabel(String),
+    SetEnd(String),
+    SetAction(CountdownAction),
+    Start,
+    Stop,
+}
+
+/// The countdown shown on the song windows, started from the config window
+/// or by a static with an attached countdown.
+#[derive(Debug, Default)]
+pub struct Countdown {
+    pub running: Option<RunningCountdown>,
+    /// Countdowns attached to the statics, by index of the static.
+    attached: Vec<Option<CountdownSpec>>,
+    observed: Option<SongDataSource>,
+
+    label: String,
+    end: String,
+    action: CountdownAction,
+}
+
+impl Countdown {
+    pub fn set_attached(&mut self, attached: Vec<Option<CountdownSpec>>) {
+        self.attached = attached;
+    }
+
+    fn start(&mut self, spec: CountdownSpec, static_index: Option<usize>, now: DateTime<Local>) {
+        self.running = Some(RunningCountdown {
+            label: spec.label,
+            deadline: spec.end.deadline(now),
+            action: spec.action,
+            static_index,
+        });
+    }
+
+    pub fn update(&mut self, message: CountdownMessage, now: DateTime<Local>) {
+        match message {
+            CountdownMessage::SetLabel(label) => self.label = label,
+            CountdownMessage::SetEnd(end) => self.end = end,
+            CountdownMessage::SetAction(action) => self.action = action,
+            CountdownMessage::Start => {
+                let Some(end) = CountdownEnd::parse(&self.end) else {
+                    return;
+                };
+
+                let spec = CountdownSpec {
+                    label: self.label.trim().to_owned(),
+                    end,
+                    action: self.action.clone(),
+                };
+                self.start(spec, None, now);
+            }
+            CountdownMessage::Stop => self.running = None,
+        }
+    }
+
+    /// Starts the countdown of a static when it is shown, and stops it when
+    /// another song is shown.
+    pub fn observe(&mut self, source: &SongDataSource, now: DateTime<Local>) {
+        if self.observed.as_ref() == Some(source) {
+            return;
+        }
+        self.observed = Some(source.clone());
+
+        if self
+            .running
+            .as_ref()
+            .is_some_and(|r| r.static_index.is_some())
+        {
+            self.running = None;
+        }
+
+        if let SongDataSource::Static(i) = *source
+            && let Some(Some(spec)) = self.attached.get(i)
+        {
+            self.start(spec.clone(), Some(i), now);
+        }
+    }
+
+    /// Stops the countdown if it reached zero and returns its action.
+    pub fn take_expired(&mut self, now: DateTime<Local>) -> Option<CountdownAction> {
+        if self.running.as_ref()?.deadline > now {
+            return None;
+        }
+
+        self.running.take().map(|r| r.action)
+    }
+
+    /// Builds the inputs for a new countdown and the running countdown, for the countdown menu.
+    pub fn build_editor(&self, statics: &[SongInfo]) -> Vec<Element<'_, Message>> {
+        let msg = Message::Countdown;
+
+        let mut actions = vec![CountdownAction::Nothing, CountdownAction::NextSong];
+        actions.extend(
+            statics
+                .iter()
+                .map(|s| CountdownAction::Static(s.dance.clone())),
+        );
+
+        let mut rows: Vec<Element<_>> = vec![
+            text_input(tr("Label, e.g. Buffet opens in"), &self.label)
+                .on_input(move |v| msg(CountdownMessage::SetLabel(v)))
+                .width(Length::Fill)
+                .into(),
+            row![
+                text_input(tr("5:00 or @19:30"), &self.end)
+                    .on_input(move |v| msg(CountdownMessage::SetEnd(v)))
+                    .on_submit(msg(CountdownMessage::Start))
+                    .width(Length::Fixed(110.0)),
+                pick_list(actions, Some(self.action.clone()), move |action| {
+                    msg(CountdownMessage::SetAction(action))
+                })
+                .width(Length::Fill),
+                label_message_button_shrink(tr("Start"), msg(CountdownMessage::Start)),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+        ];
+
+        if let Some(running) = &self.running {
+            rows.push(
+                row![
+                    text(running.text(Local::now())).width(Length::Fill),
+                    label_message_button_shrink(tr("Stop"), msg(CountdownMessage::Stop)),
+                ]
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into(),
+            );
+        }
+
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dataprovider::song_data_provider::SongDataSource;
+    use crate::ui::config_window::countdown::{
+        Countdown, CountdownAction, CountdownEnd, CountdownMessage, CountdownSpec,
+    };
+    use chrono::{Local, NaiveTime, TimeDelta, TimeZone};
+    use std::time::Duration;
+
+    #[test]
+    fn static_countdowns_run_while_the_static_is_shown() {
+        let spec = CountdownSpec::parse(" @19:30 | Buffet opens in | next").unwrap();
+        assert_eq!(
+            spec.end,
+            CountdownEnd::At(NaiveTime::from_hms_opt(19, 30, 0).unwrap())
+        );
+        assert_eq!(spec.label, "Buffet opens in");
+        assert_eq!(spec.action, CountdownAction::NextSong);
+        assert_eq!(
+            CountdownSpec::parse("5:00").map(|s| s.end),
+            Some(CountdownEnd::After(Duration::from_secs(300)))
+        );
+        assert!(CountdownSpec::parse("soon").is_none());
+        assert_eq!(CountdownSpec::parse_static("Break | 15:00").0, "Break");
+        assert_eq!(
+            CountdownSpec::parse_static("Cha Cha | Rumba"),
+            ("Cha Cha | Rumba", None)
+        );
+
+        let now = Local.with_ymd_and_hms(2024, 5, 1, 19, 17, 30).unwrap();
+        let mut countdown = Countdown::default();
+        countdown.set_attached(vec![None, Some(spec)]);
+
+        countdown.observe(&SongDataSource::Static(1), now);
+        let running = countdown.running.as_ref().unwrap();
+        assert_eq!(running.text(now), "Buffet opens in 12:30");
+
+        assert!(countdown.take_expired(now).is_none());
+        assert_eq!(
+            countdown.take_expired(now + TimeDelta::minutes(13)),
+            Some(CountdownAction::NextSong)
+        );
+
+        countdown.observe(&SongDataSource::Blank, now);
+        countdown.observe(&SongDataSource::Static(1), now);
+        countdown.observe(&SongDataSource::Playlist(0), now);
+        assert!(countdown.running.is_none());
+
+        countdown.update(CountdownMessage::SetEnd("120:00".to_owned()), now);
+        countdown.update(CountdownMessage::Start, now);
+        assert_eq!(countdown.running.as_ref().unwrap().text(now), "2:00:00");
+    }
+}
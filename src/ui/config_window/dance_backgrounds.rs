@@ -0,0 +1,342 @@
+use crate::Message;
+use crate::dataloading::dances::dance_category;
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::tr;
+use crate::ui::color::HexColor;
+use crate::ui::config_window::{
+    label_message_button_shrink, labeled_message_checkbox, material_icon_message_button,
+};
+use crate::ui::song_window::background::{
+    Background, BackgroundCache, BackgroundFill, BackgroundKind,
+};
+use iced::alignment::Vertical;
+use iced::widget::{button, pick_list, row, text, text_input};
+use iced::{Element, Length};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Song window background for a dance or a whole dance category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DanceBackground {
+    pub name: String,
+    pub background: Background,
+}
+
+/// Changes to the backgrounds. The `Option<usize>` is the index of the
+/// entry, or `None` for the default background.
+#[derive(Debug, Clone)]
+pub enum DanceBackgroundsMessage {
+    SetKind(Option<usize>, BackgroundKind),
+    /// Sets the first or second colour of a gradient, or the plain colour.
+    SetColor(Option<usize>, usize, String),
+    SetImage(Option<usize>, PathBuf),
+    Remove(usize),
+    EnableAlbumArt(bool),
+    SetNewName(String),
+    Add,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DanceBackgrounds {
+    pub entries: Vec<DanceBackground>,
+    /// Shown when no entry matches the dance, the layout colour if unset.
+    pub default: Option<Background>,
+    /// Shows the blurred album art when no entry matches the dance.
+    pub album_art: bool,
+    #[serde(skip)]
+    new_name: String,
+}
+
+impl DanceBackgrounds {
+    /// Returns the background of the dance. A background set for the dance
+    /// itself takes precedence over the background of its category.
+    pub fn background_for(&self, dance: &str) -> Option<&Background> {
+        let dance = dance.trim();
+        if dance.is_empty() {
+            return None;
+        }
+
+        let find = |name: &str| {
+            self.entries
+                .iter()
+                .find(|e| e.name.trim().eq_ignore_ascii_case(name))
+                .map(|e| &e.background)
+        };
+
+        find(dance).or_else(|| dance_category(dance).and_then(find))
+    }
+
+    /// Returns what to draw behind the song: the background of its dance,
+    /// its blurred album art or the default background, whichever is
+    /// available first. `None` leaves the background colour of the layout.
+    pub fn fill_for(
+        &self,
+        song: Option<&SongInfo>,
+        cache: &BackgroundCache,
+    ) -> Option<BackgroundFill> {
+        let fill = |background: &Background| match background {
+            Background::Color(color) => Some(BackgroundFill::Color(color.color())),
+            Background::Gradient(top, bottom) => {
+                Some(BackgroundFill::gradient(top.color(), bottom.color()))
+            }
+            Background::Image(path) if path.as_os_str().is_empty() => None,
+            Background::Image(path) => cache.image(path).map(BackgroundFill::Image),
+        };
+
+        song.and_then(|s| self.background_for(&s.dance))
+            .and_then(fill)
+            .or_else(|| {
+                song.and_then(|s| s.album_art.as_ref())
+                    .filter(|_| self.album_art)
+                    .and_then(|art| cache.blurred(art))
+                    .map(BackgroundFill::Image)
+            })
+            .or_else(|| self.default.as_ref().and_then(fill))
+    }
+
+    fn background(&self, slot: Option<usize>) -> Option<&Background> {
+        match slot {
+            None => self.default.as_ref(),
+            Some(i) => self.entries.get(i).map(|e| &e.background),
+        }
+    }
+
+    /// Replaces a background. Entries cannot be left without one.
+    fn set_background(&mut self, slot: Option<usize>, background: Option<Background>) -> bool {
+        match (slot, background) {
+            (None, background) => {
+                self.default = background;
+                true
+            }
+            (Some(i), Some(background)) => match self.entries.get_mut(i) {
+                Some(entry) => {
+                    entry.background = background;
+                    true
+                }
+                None => false,
+            },
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Applies a change. Returns whether the persisted backgrounds changed.
+    pub fn update(&mut self, message: DanceBackgroundsMessage) -> bool {
+        match message {
+            DanceBackgroundsMessage::SetKind(slot, kind) => {
+                let current = self.background(slot).cloned();
+                let background = Background::with_kind(current.as_ref(), kind);
+
+                self.set_background(slot, background)
+            }
+            DanceBackgroundsMessage::SetColor(slot, stop, hex) => {
+                let mut background = self.background(slot).cloned();
+                let color = match (&mut background, stop) {
+                    (Some(Background::Color(color)), _)
+                    | (Some(Background::Gradient(color, _)), 0)
+                    | (Some(Background::Gradient(_, color)), _) => color,
+                    _ => return false,
+                };
+
+                // the draft is kept while typing, but only valid colours are saved
+                let valid = color.set_hex(hex);
+                self.set_background(slot, background);
+                valid
+            }
+            DanceBackgroundsMessage::SetImage(slot, path) => {
+                self.set_background(slot, Some(Background::Image(path)))
+            }
+            DanceBackgroundsMessage::Remove(i) => {
+                if i < self.entries.len() {
+                    self.entries.remove(i);
+                    true
+                } else {
+                    false
+                }
+            }
+            DanceBackgroundsMessage::EnableAlbumArt(state) => {
+                self.album_art = state;
+                true
+            }
+            DanceBackgroundsMessage::SetNewName(name) => {
+                self.new_name = name;
+                false
+            }
+            DanceBackgroundsMessage::Add => {
+                let name = self.new_name.trim();
+                if name.is_empty()
+                    || self
+                        .entries
+                        .iter()
+                        .any(|e| e.name.trim().eq_ignore_ascii_case(name))
+                {
+                    return false;
+                }
+
+                self.entries.push(DanceBackground {
+                    name: name.to_owned(),
+                    background: Background::Color(HexColor::new([0x80, 0x80, 0x80])),
+                });
+                self.new_name.clear();
+                true
+            }
+        }
+    }
+
+    /// Builds the default background, one row per dance or category plus a
+    /// row to add a new one, for the background menu.
+    pub fn build_editor(&self) -> Vec<Element<'_, Message>> {
+        let msg = Message::DanceBackgrounds;
+
+        let mut rows: Vec<Element<_>> = vec![
+            row![text(tr("Default")).width(Length::Fill)]
+                .extend(background_inputs(
+                    None,
+                    self.default.as_ref(),
+                    &BackgroundKind::ALL,
+                ))
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into(),
+            labeled_message_checkbox(tr("Blurred Album Art"), self.album_art, |state| {
+                Message::DanceBackgrounds(DanceBackgroundsMessage::EnableAlbumArt(state))
+            })
+            .into(),
+        ];
+
+        rows.extend(self.entries.iter().enumerate().map(|(i, entry)| {
+            row![text(&entry.name).width(Length::Fill)]
+                .extend(background_inputs(
+                    Some(i),
+                    Some(&entry.background),
+                    &BackgroundKind::ALL[1..],
+                ))
+                .push(material_icon_message_button(
+                    "delete",
+                    msg(DanceBackgroundsMessage::Remove(i)),
+                ))
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into()
+        }));
+
+        rows.push(
+            row![
+                text_input(tr("Dance or category"), &self.new_name)
+                    .on_input(move |v| msg(DanceBackgroundsMessage::SetNewName(v)))
+                    .on_submit(msg(DanceBackgroundsMessage::Add))
+                    .width(Length::Fill),
+                label_message_button_shrink(tr("Add"), msg(DanceBackgroundsMessage::Add)),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+        );
+
+        rows
+    }
+}
+
+/// Builds the kind chooser and the inputs for the colours or the image of a background.
+fn background_inputs<'a>(
+    slot: Option<usize>,
+    background: Option<&'a Background>,
+    kinds: &'a [BackgroundKind],
+) -> Vec<Element<'a, Message>> {
+    let msg = Message::DanceBackgrounds;
+    let color_input = |stop: usize, color: &HexColor| {
+        text_input("#rrggbb", &color.hex())
+            .on_input(move |v| msg(DanceBackgroundsMessage::SetColor(slot, stop, v)))
+            .width(Length::Fixed(80.0))
+            .into()
+    };
+
+    let mut inputs = vec![
+        pick_list(
+            kinds,
+            Some(background.map_or(BackgroundKind::Layout, Background::kind)),
+            move |kind| msg(DanceBackgroundsMessage::SetKind(slot, kind)),
+        )
+        .into(),
+    ];
+
+    match background {
+        Some(Background::Color(color)) => inputs.push(color_input(0, color)),
+        Some(Background::Gradient(top, bottom)) => {
+            inputs.push(color_input(0, top));
+            inputs.push(color_input(1, bottom));
+        }
+        Some(Background::Image(path)) => {
+            let label = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| tr("Choose Image").to_owned());
+
+            inputs.push(
+                button(text(label))
+                    .style(button::secondary)
+                    .on_press(Message::PickBackgroundImage(slot))
+                    .width(Length::Fixed(165.0))
+                    .into(),
+            );
+        }
+        None => {}
+    }
+
+    inputs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::songinfo::SongInfo;
+    use crate::ui::config_window::dance_backgrounds::{DanceBackgrounds, DanceBackgroundsMessage};
+    use crate::ui::song_window::background::{BackgroundCache, BackgroundFill, BackgroundKind};
+    use iced::Color;
+
+    #[test]
+    fn dances_override_their_category_and_the_default() {
+        let mut backgrounds = DanceBackgrounds::default();
+        for name in ["Latein", "Rumba"] {
+            backgrounds.update(DanceBackgroundsMessage::SetNewName(name.to_owned()));
+            assert!(backgrounds.update(DanceBackgroundsMessage::Add));
+        }
+        backgrounds.update(DanceBackgroundsMessage::SetColor(
+            Some(0),
+            0,
+            "#ff0000".to_owned(),
+        ));
+        backgrounds.update(DanceBackgroundsMessage::SetColor(
+            Some(1),
+            0,
+            "#00ff00".to_owned(),
+        ));
+        backgrounds.update(DanceBackgroundsMessage::SetKind(
+            None,
+            BackgroundKind::Color,
+        ));
+        backgrounds.update(DanceBackgroundsMessage::SetColor(
+            None,
+            0,
+            "#0000ff".to_owned(),
+        ));
+
+        let cache = BackgroundCache::default();
+        let color = |dance: &str| match backgrounds
+            .fill_for(Some(&SongInfo::with_dance(dance.to_owned())), &cache)
+        {
+            Some(BackgroundFill::Color(color)) => Some(color),
+            _ => None,
+        };
+
+        assert_eq!(color("Samba"), Some(Color::from_rgb8(0xff, 0x00, 0x00)));
+        assert_eq!(color(" rumba"), Some(Color::from_rgb8(0x00, 0xff, 0x00)));
+        assert_eq!(color("Tango"), Some(Color::from_rgb8(0x00, 0x00, 0xff)));
+
+        backgrounds.update(DanceBackgroundsMessage::SetKind(
+            None,
+            BackgroundKind::Layout,
+        ));
+        let tango = SongInfo::with_dance("Tango".to_owned());
+        assert!(backgrounds.fill_for(Some(&tango), &cache).is_none());
+    }
+}
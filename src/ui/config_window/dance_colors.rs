@@ -0,0 +1,240 @@
+use crate::Message;
+use crate::dataloading::dances::dance_category;
+use crate::i18n::tr;
+use crate::ui::color::HexColor;
+use crate::ui::config_window::{label_message_button_shrink, material_icon_message_button};
+use iced::alignment::Vertical;
+use iced::widget::{Space, button, container, row, text, text_input};
+use iced::{Border, Color, Element, Length, Theme};
+use serde::{Deserialize, Serialize};
+
+/// Colour assigned to a dance or to a whole dance category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DanceColor {
+    pub name: String,
+    pub rgb: HexColor,
+}
+
+impl DanceColor {
+    fn new(name: &str, rgb: [u8; 3]) -> Self {
+        Self {
+            name: name.to_owned(),
+            rgb: HexColor::new(rgb),
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        self.rgb.color()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DanceColorsMessage {
+    SetColor(usize, String),
+    Remove(usize),
+    SetNewName(String),
+    Add,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DanceColors {
+    pub entries: Vec<DanceColor>,
+    #[serde(skip)]
+    new_name: String,
+}
+
+impl Default for DanceColors {
+    fn default() -> Self {
+        Self {
+            entries: vec![
+                DanceColor::new("Standard", [0x42, 0x85, 0xf4]),
+                DanceColor::new("Latein", [0xe6, 0x51, 0x00]),
+            ],
+            new_name: String::new(),
+        }
+    }
+}
+
+impl DanceColors {
+    /// Returns the colour of the dance. A colour set for the dance itself
+    /// takes precedence over the colour of its category.
+    pub fn color_for(&self, dance: &str) -> Option<Color> {
+        let dance = dance.trim();
+        if dance.is_empty() {
+            return None;
+        }
+
+        let find = |name: &str| {
+            self.entries
+                .iter()
+                .find(|e| e.name.trim().eq_ignore_ascii_case(name))
+                .map(DanceColor::color)
+        };
+
+        find(dance).or_else(|| dance_category(dance).and_then(find))
+    }
+
+    /// Applies a change. Returns whether the persisted colours changed.
+    pub fn update(&mut self, message: DanceColorsMessage) -> bool {
+        match message {
+            DanceColorsMessage::SetColor(i, hex) => {
+                let Some(entry) = self.entries.get_mut(i) else {
+                    return false;
+                };
+
+                entry.rgb.set_hex(hex)
+            }
+            DanceColorsMessage::Remove(i) => {
+                if i < self.entries.len() {
+                    self.entries.remove(i);
+                    true
+                } else {
+                    false
+                }
+            }
+            DanceColorsMessage::SetNewName(name) => {
+                self.new_name = name;
+                false
+            }
+            DanceColorsMessage::Add => {
+                let name = self.new_name.trim();
+                if name.is_empty()
+                    || self
+                        .entries
+                        .iter()
+                        .any(|e| e.name.trim().eq_ignore_ascii_case(name))
+                {
+                    return false;
+                }
+
+                self.entries.push(DanceColor::new(name, [0x80, 0x80, 0x80]));
+                self.new_name.clear();
+                true
+            }
+        }
+    }
+
+    /// Builds one row per colour plus a row to add a new one, for the colour menu.
+    pub fn build_editor(&self) -> Vec<Element<'_, Message>> {
+        let msg = Message::DanceColors;
+
+        let mut rows: Vec<Element<_>> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let color = entry.color();
+
+                row![
+                    container(Space::new().width(16).height(16)).style(move |_: &Theme| {
+                        container::Style::default()
+                            .background(color)
+                            .border(Border::default().rounded(2))
+                    }),
+                    text(&entry.name).width(Length::Fill),
+                    text_input("#rrggbb", &entry.rgb.hex())
+                        .on_input(move |v| msg(DanceColorsMessage::SetColor(i, v)))
+                        .width(Length::Fixed(80.0)),
+                    material_icon_message_button("delete", msg(DanceColorsMessage::Remove(i))),
+                ]
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into()
+            })
+            .collect();
+
+        rows.push(
+            row![
+                text_input(tr("Dance or category"), &self.new_name)
+                    .on_input(move |v| msg(DanceColorsMessage::SetNewName(v)))
+                    .on_submit(msg(DanceColorsMessage::Add))
+                    .width(Length::Fill),
+                label_message_button_shrink(tr("Add"), msg(DanceColorsMessage::Add)),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+        );
+
+        rows
+    }
+}
+
+fn relative_luminance(color: Color) -> f32 {
+    0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b
+}
+
+/// Adjusts the colour so it stands out against the background of the theme:
+/// dark colours are lightened on dark themes and light colours darkened on light ones.
+pub fn accent_color(color: Color, theme: &Theme) -> Color {
+    let luminance = relative_luminance(color);
+    let is_dark = theme.extended_palette().is_dark;
+
+    if is_dark && luminance < 0.35 {
+        mix(color, Color::WHITE, 0.35)
+    } else if !is_dark && luminance > 0.65 {
+        mix(color, Color::BLACK, 0.35)
+    } else {
+        color
+    }
+}
+
+/// Returns black or white, whichever is more readable on the given background.
+pub fn text_color_on(background: Color) -> Color {
+    if relative_luminance(background) > 0.5 {
+        Color::BLACK
+    } else {
+        Color::WHITE
+    }
+}
+
+fn mix(a: Color, b: Color, factor: f32) -> Color {
+    Color::from_rgb(
+        a.r + (b.r - a.r) * factor,
+        a.g + (b.g - a.g) * factor,
+        a.b + (b.b - a.b) * factor,
+    )
+}
+
+/// Button style with the dance colour as background.
+pub fn colored_button_style(
+    color: Color,
+) -> impl Fn(&Theme, button::Status) -> button::Style + 'static {
+    move |theme, status| {
+        let background = match status {
+            button::Status::Hovered | button::Status::Pressed => mix(
+                accent_color(color, theme),
+                theme.extended_palette().background.base.text,
+                0.15,
+            ),
+            _ => accent_color(color, theme),
+        };
+
+        button::Style {
+            background: Some(background.into()),
+            text_color: text_color_on(background),
+            ..button::secondary(theme, status)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::config_window::dance_colors::{DanceColors, DanceColorsMessage};
+    use iced::Color;
+
+    #[test]
+    fn dance_color_overrides_category_color() {
+        let mut colors = DanceColors::default();
+        colors.update(DanceColorsMessage::SetNewName("rumba".to_owned()));
+        colors.update(DanceColorsMessage::Add);
+        colors.update(DanceColorsMessage::SetColor(2, "#00ff".to_owned()));
+        assert_eq!(colors.entries[2].rgb.hex(), "#00ff");
+
+        colors.update(DanceColorsMessage::SetColor(2, "#00ff00".to_owned()));
+
+        assert_eq!(colors.color_for("Rumba"), Some(Color::from_rgb8(0, 255, 0)));
+        assert_eq!(colors.color_for("Jive"), Some(colors.entries[1].color()));
+        assert_eq!(colors.color_for("Discofox"), None);
+    }
+}
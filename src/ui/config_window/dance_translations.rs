@@ -0,0 +1,193 @@
+use crate::Message;
+use crate::dataloading::dance_translations::DanceTranslations;
+use crate::i18n::tr;
+use crate::ui::config_window::{label_message_button_shrink, material_icon_message_button};
+use crate::ui::widget::on_blur::OnBlur;
+use iced::alignment::Vertical;
+use iced::widget::{row, text, text_input};
+use iced::{Element, Length};
+
+#[derive(Debug, Clone)]
+pub enum DanceTranslationsMessage {
+    SetTranslation(usize, String),
+    CommitTranslation,
+    Remove(usize),
+    SetNewDance(String),
+    SetNewTranslation(String),
+    Add,
+}
+
+/// Menu to edit the dance translations, with the inputs for a new translation.
+#[derive(Debug, Default)]
+pub struct DanceTranslationsEditor {
+    /// Index and text of the translation being typed, committed on submit,
+    /// when its input loses the focus or with the next other change.
+    draft: Option<(usize, String)>,
+    new_dance: String,
+    new_translation: String,
+}
+
+impl DanceTranslationsEditor {
+    /// Applies a change. Returns whether the persisted translations changed.
+    pub fn update(
+        &mut self,
+        message: DanceTranslationsMessage,
+        translations: &mut DanceTranslations,
+    ) -> bool {
+        let committed = match message {
+            DanceTranslationsMessage::SetTranslation(i, _) if self.is_drafting(i) => false,
+            _ => self.commit_draft(translations),
+        };
+
+        self.apply(message, translations) || committed
+    }
+
+    fn is_drafting(&self, i: usize) -> bool {
+        self.draft.as_ref().is_some_and(|(j, _)| *j == i)
+    }
+
+    fn commit_draft(&mut self, translations: &mut DanceTranslations) -> bool {
+        let Some((i, translation)) = self.draft.take() else {
+            return false;
+        };
+        let Some(entry) = translations.entries.get_mut(i) else {
+            return false;
+        };
+        if entry.translation == translation {
+            return false;
+        }
+
+        entry.translation = translation;
+        true
+    }
+
+    fn apply(
+        &mut self,
+        message: DanceTranslationsMessage,
+        translations: &mut DanceTranslations,
+    ) -> bool {
+        match message {
+            DanceTranslationsMessage::SetTranslation(i, translation) => {
+                self.draft = Some((i, translation));
+                false
+            }
+            // the draft was already committed in `update`
+            DanceTranslationsMessage::CommitTranslation => false,
+            DanceTranslationsMessage::Remove(i) => {
+                if i < translations.entries.len() {
+                    translations.entries.remove(i);
+                    true
+                } else {
+                    false
+                }
+            }
+            DanceTranslationsMessage::SetNewDance(dance) => {
+                self.new_dance = dance;
+                false
+            }
+            DanceTranslationsMessage::SetNewTranslation(translation) => {
+                self.new_translation = translation;
+                false
+            }
+            DanceTranslationsMessage::Add => {
+                if !translations.add(&self.new_dance, &self.new_translation) {
+                    return false;
+                }
+
+                self.new_dance.clear();
+                self.new_translation.clear();
+                true
+            }
+        }
+    }
+
+    /// Builds one row per translation plus a row to add a new one, for the translation menu.
+    pub fn build<'a>(&'a self, translations: &'a DanceTranslations) -> Vec<Element<'a, Message>> {
+        let msg = Message::DanceTranslations;
+
+        let mut rows: Vec<Element<_>> = translations
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let translation = match &self.draft {
+                    Some((j, draft)) if *j == i => draft,
+                    _ => &entry.translation,
+                };
+
+                row![
+                    text(&entry.dance).width(Length::Fill),
+                    OnBlur::new(
+                        text_input(tr("Translation"), translation)
+                            .on_input(move |v| msg(DanceTranslationsMessage::SetTranslation(i, v)))
+                            .on_submit(msg(DanceTranslationsMessage::CommitTranslation))
+                            .width(Length::Fill),
+                        msg(DanceTranslationsMessage::CommitTranslation),
+                    ),
+                    material_icon_message_button(
+                        "delete",
+                        msg(DanceTranslationsMessage::Remove(i))
+                    ),
+                ]
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into()
+            })
+            .collect();
+
+        rows.push(
+            row![
+                text_input(tr("Dance"), &self.new_dance)
+                    .on_input(move |v| msg(DanceTranslationsMessage::SetNewDance(v)))
+                    .on_submit(msg(DanceTranslationsMessage::Add))
+                    .width(Length::Fill),
+                text_input(tr("Translation"), &self.new_translation)
+                    .on_input(move |v| msg(DanceTranslationsMessage::SetNewTranslation(v)))
+                    .on_submit(msg(DanceTranslationsMessage::Add))
+                    .width(Length::Fill),
+                label_message_button_shrink(tr("Add"), msg(DanceTranslationsMessage::Add)),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+        );
+
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dance_translations::DanceTranslations;
+    use crate::ui::config_window::dance_translations::{
+        DanceTranslationsEditor, DanceTranslationsMessage,
+    };
+
+    #[test]
+    fn translations_are_saved_once_committed() {
+        let mut translations = DanceTranslations::default();
+        let mut editor = DanceTranslationsEditor::default();
+        let set = |text: &str| DanceTranslationsMessage::SetTranslation(0, text.to_owned());
+
+        assert!(!editor.update(set("W"), &mut translations));
+        assert!(!editor.update(set("Wa"), &mut translations));
+        assert_ne!(translations.entries[0].translation, "Wa");
+
+        assert!(editor.update(
+            DanceTranslationsMessage::CommitTranslation,
+            &mut translations
+        ));
+        assert_eq!(translations.entries[0].translation, "Wa");
+        assert!(!editor.update(
+            DanceTranslationsMessage::CommitTranslation,
+            &mut translations
+        ));
+
+        assert!(!editor.update(set("Waltz"), &mut translations));
+        assert!(editor.update(
+            DanceTranslationsMessage::SetNewDance("Polka".to_owned()),
+            &mut translations
+        ));
+        assert_eq!(translations.entries[0].translation, "Waltz");
+    }
+}
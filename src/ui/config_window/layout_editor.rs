@@ -0,0 +1,316 @@
+use crate::Message;
+use crate::i18n::tr;
+use crate::ui::config_window::{label_message_button_shrink, material_icon_message_button};
+use crate::ui::song_window::layout::{
+    FontFamily, FontName, FontWeight, HorizontalAlign, LayoutElementKind, LayoutProfile,
+    LayoutProfiles, TextOverflow, VerticalAlign,
+};
+use iced::alignment::Vertical;
+use iced::widget::{Column, column, pick_list, row, slider, text, text_input};
+use iced::{Length, Theme, widget::container};
+
+#[derive(Debug, Clone)]
+pub enum LayoutEditorMessage {
+    Toggle,
+    SelectProfile(String),
+    SetNewProfileName(String),
+    AddProfile,
+    RemoveProfile,
+    SelectElement(LayoutElementKind),
+    SetBackground(String),
+    SetFamily(FontFamily),
+    SetCustomFamily(String),
+    CommitCustomFamily,
+    SetWeight(FontWeight),
+    SetSize(f32),
+    SetColor(String),
+    SetAlign(HorizontalAlign),
+    SetVertical(VerticalAlign),
+    SetMinScale(f32),
+    SetOverflow(TextOverflow),
+    MoveUp,
+    MoveDown,
+    /// Sent when a size slider is released, so dragging does not write the settings file.
+    Commit,
+}
+
+/// Panel to edit the song window layout of the active profile.
+/// Changes are applied to the song window immediately.
+pub struct LayoutEditor {
+    pub open: bool,
+    selected: LayoutElementKind,
+    new_profile_name: String,
+    /// The custom font name being typed, applied when it is submitted or the editor is used otherwise.
+    custom_family: Option<String>,
+}
+
+impl LayoutEditor {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            selected: LayoutElementKind::Dance,
+            new_profile_name: String::new(),
+            custom_family: None,
+        }
+    }
+
+    /// Applies a change. Returns whether the persisted profiles changed.
+    pub fn update(&mut self, message: LayoutEditorMessage, profiles: &mut LayoutProfiles) -> bool {
+        let committed = match message {
+            LayoutEditorMessage::SetCustomFamily(_) => false,
+            _ => self.commit_custom_family(profiles),
+        };
+
+        self.apply(message, profiles) || committed
+    }
+
+    fn commit_custom_family(&mut self, profiles: &mut LayoutProfiles) -> bool {
+        let Some(name) = self.custom_family.take() else {
+            return false;
+        };
+
+        let element = profiles.active_mut().element_mut(self.selected);
+        if !matches!(element.family, FontFamily::Named(_)) {
+            return false;
+        }
+
+        element.family = FontFamily::Named(FontName::new(name.trim()));
+        true
+    }
+
+    fn apply(&mut self, message: LayoutEditorMessage, profiles: &mut LayoutProfiles) -> bool {
+        let selected = self.selected;
+
+        match message {
+            LayoutEditorMessage::Toggle => {
+                self.open = !self.open;
+                false
+            }
+            LayoutEditorMessage::SelectProfile(name) => {
+                let Some(i) = profiles.profiles.iter().position(|p| p.name == name) else {
+                    return false;
+                };
+
+                profiles.active = i;
+                true
+            }
+            LayoutEditorMessage::SetNewProfileName(name) => {
+                self.new_profile_name = name;
+                false
+            }
+            LayoutEditorMessage::AddProfile => {
+                let name = self.new_profile_name.trim();
+                if name.is_empty() || profiles.profiles.iter().any(|p| p.name == name) {
+                    return false;
+                }
+
+                // new profiles start as a copy of the active one
+                let layout = profiles.active().clone();
+                profiles.profiles.push(LayoutProfile {
+                    name: name.to_owned(),
+                    layout,
+                });
+                profiles.active = profiles.profiles.len() - 1;
+                self.new_profile_name.clear();
+                true
+            }
+            LayoutEditorMessage::RemoveProfile => {
+                if profiles.profiles.len() <= 1 || profiles.active >= profiles.profiles.len() {
+                    return false;
+                }
+
+                profiles.profiles.remove(profiles.active);
+                profiles.active = profiles.active.saturating_sub(1);
+                true
+            }
+            LayoutEditorMessage::SelectElement(kind) => {
+                self.selected = kind;
+                false
+            }
+            LayoutEditorMessage::SetBackground(hex) => {
+                profiles.active_mut().background.set_hex(hex)
+            }
+            LayoutEditorMessage::SetFamily(family) => {
+                profiles.active_mut().element_mut(selected).family = family;
+                true
+            }
+            LayoutEditorMessage::SetCustomFamily(name) => {
+                self.custom_family = Some(name);
+                false
+            }
+            // the draft was already committed in `update`
+            LayoutEditorMessage::CommitCustomFamily => false,
+            LayoutEditorMessage::SetWeight(weight) => {
+                profiles.active_mut().element_mut(selected).weight = weight;
+                true
+            }
+            LayoutEditorMessage::SetSize(size) => {
+                profiles.active_mut().element_mut(selected).size = size;
+                false
+            }
+            LayoutEditorMessage::SetColor(hex) => profiles
+                .active_mut()
+                .element_mut(selected)
+                .color
+                .set_hex(hex),
+            LayoutEditorMessage::SetAlign(align) => {
+                profiles.active_mut().element_mut(selected).align = align;
+                true
+            }
+            LayoutEditorMessage::SetVertical(vertical) => {
+                profiles.active_mut().element_mut(selected).vertical = vertical;
+                true
+            }
+            LayoutEditorMessage::SetMinScale(min_scale) => {
+                profiles.active_mut().element_mut(selected).min_scale = min_scale;
+                false
+            }
+            LayoutEditorMessage::SetOverflow(overflow) => {
+                profiles.active_mut().element_mut(selected).overflow = overflow;
+                true
+            }
+            LayoutEditorMessage::MoveUp => {
+                profiles.active_mut().move_element(selected, -1);
+                true
+            }
+            LayoutEditorMessage::MoveDown => {
+                profiles.active_mut().move_element(selected, 1);
+                true
+            }
+            LayoutEditorMessage::Commit => true,
+        }
+    }
+
+    pub fn build<'a>(&'a self, profiles: &'a LayoutProfiles) -> Column<'a, Message> {
+        let msg = Message::LayoutEditor;
+
+        if !self.open {
+            return column![];
+        }
+
+        let layout = profiles.active();
+        let style = layout.element(self.selected);
+
+        let profile_row = row![
+            text(tr("Profile")),
+            pick_list(profiles.names(), profiles.active_name(), move |name| {
+                msg(LayoutEditorMessage::SelectProfile(name))
+            })
+            .width(Length::FillPortion(2)),
+            material_icon_message_button("delete", msg(LayoutEditorMessage::RemoveProfile)),
+            text_input(tr("New profile"), &self.new_profile_name)
+                .on_input(move |v| msg(LayoutEditorMessage::SetNewProfileName(v)))
+                .on_submit(msg(LayoutEditorMessage::AddProfile))
+                .width(Length::FillPortion(2)),
+            label_message_button_shrink(tr("Add"), msg(LayoutEditorMessage::AddProfile)),
+            text(tr("Background")),
+            text_input("#rrggbb", &layout.background.hex())
+                .on_input(move |v| msg(LayoutEditorMessage::SetBackground(v)))
+                .width(Length::Fixed(80.0)),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center);
+
+        let custom_family = match &style.family {
+            FontFamily::Named(name) => *name,
+            _ => FontName::default(),
+        };
+        let families = vec![
+            FontFamily::SansSerif,
+            FontFamily::Serif,
+            FontFamily::Monospace,
+            FontFamily::Named(custom_family),
+        ];
+
+        let mut font_row = row![
+            pick_list(LayoutElementKind::ALL, Some(self.selected), move |k| {
+                msg(LayoutEditorMessage::SelectElement(k))
+            }),
+            pick_list(families, Some(style.family.clone()), move |f| {
+                msg(LayoutEditorMessage::SetFamily(f))
+            }),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center);
+
+        if matches!(style.family, FontFamily::Named(_)) {
+            font_row = font_row.push(
+                text_input(
+                    tr("Font name"),
+                    self.custom_family
+                        .as_deref()
+                        .unwrap_or(custom_family.as_str()),
+                )
+                .on_input(move |v| msg(LayoutEditorMessage::SetCustomFamily(v)))
+                .on_submit(msg(LayoutEditorMessage::CommitCustomFamily))
+                .width(Length::Fixed(140.0)),
+            );
+        }
+
+        font_row = font_row.push(pick_list(FontWeight::ALL, Some(style.weight), move |w| {
+            msg(LayoutEditorMessage::SetWeight(w))
+        }));
+
+        let mut style_row = row![
+            text(format!("{} {:.1}%", tr("Size"), style.size)),
+            slider(1.0..=40.0, style.size, move |s| {
+                msg(LayoutEditorMessage::SetSize(s))
+            })
+            .step(0.5)
+            .on_release(msg(LayoutEditorMessage::Commit))
+            .width(Length::Fill),
+            text(tr("Colour")),
+            text_input("#rrggbb", &style.color.hex())
+                .on_input(move |v| msg(LayoutEditorMessage::SetColor(v)))
+                .width(Length::Fixed(80.0)),
+            pick_list(HorizontalAlign::ALL, Some(style.align), move |a| {
+                msg(LayoutEditorMessage::SetAlign(a))
+            }),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center);
+
+        style_row = if self.selected.is_central() {
+            style_row
+                .push(material_icon_message_button(
+                    "arrow_upward",
+                    msg(LayoutEditorMessage::MoveUp),
+                ))
+                .push(material_icon_message_button(
+                    "arrow_downward",
+                    msg(LayoutEditorMessage::MoveDown),
+                ))
+        } else {
+            style_row.push(pick_list(
+                VerticalAlign::ALL,
+                Some(style.vertical),
+                move |v| msg(LayoutEditorMessage::SetVertical(v)),
+            ))
+        };
+
+        let fit_row = row![
+            text(format!("{} {:.0}%", tr("Min. Size"), style.min_scale)),
+            slider(20.0..=100.0, style.min_scale, move |s| {
+                msg(LayoutEditorMessage::SetMinScale(s))
+            })
+            .step(5.0)
+            .on_release(msg(LayoutEditorMessage::Commit))
+            .width(Length::Fill),
+            pick_list(TextOverflow::ALL, Some(style.overflow), move |o| {
+                msg(LayoutEditorMessage::SetOverflow(o))
+            }),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center);
+
+        column![
+            container(column![profile_row, font_row, style_row, fit_row].spacing(5))
+                .padding(5)
+                .width(Length::Fill)
+                .style(|t: &Theme| {
+                    container::Style::default()
+                        .background(t.extended_palette().background.weakest.color)
+                })
+        ]
+    }
+}
@@ -1,26 +1,57 @@
 pub mod bottombar;
+pub mod countdown;
+pub mod dance_backgrounds;
+pub mod dance_colors;
+pub mod dance_translations;
+pub mod layout_editor;
+pub mod playlist_columns;
+pub mod playlist_filter;
+pub mod playlist_grid;
+pub mod playlist_selection;
 pub mod sidebar;
 
+use crate::dataloading::dance_translations::DanceTranslations;
 use crate::dataloading::dataprovider::song_data_provider::{
-    SongChange, SongDataEdit, SongDataSource,
+    QueueEdit, SongChange, SongDataEdit, SongDataProvider, SongDataSource,
 };
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::{LanguageSetting, tr};
 use crate::ui::config_window::bottombar::Bottombar;
+use crate::ui::config_window::countdown::Countdown;
+use crate::ui::config_window::dance_backgrounds::DanceBackgrounds;
+use crate::ui::config_window::dance_colors::{DanceColors, accent_color};
+use crate::ui::config_window::dance_translations::DanceTranslationsEditor;
+use crate::ui::config_window::layout_editor::{LayoutEditor, LayoutEditorMessage};
+use crate::ui::config_window::playlist_columns::{ColumnSettings, PlaylistColumn, PlaylistColumns};
+use crate::ui::config_window::playlist_filter::PlaylistFilter;
+use crate::ui::config_window::playlist_grid::{PlaylistGrid, PlaylistGridMessage};
+use crate::ui::config_window::playlist_selection::{PlaylistSelection, PlaylistSelectionMessage};
 use crate::ui::config_window::sidebar::Sidebar;
+use crate::ui::song_window::background::BackgroundCache;
+use crate::ui::song_window::progress::{ProgressDisplay, format_remaining};
+use crate::ui::song_window::readability::{Readability, Rotation};
+use crate::ui::song_window::template::SongWindowTemplate;
+use crate::ui::song_window::transition::{TransitionKind, TransitionSettings};
+use crate::ui::song_window::{SongDisplay, SongWindow};
+use crate::ui::widget::click_outside::ClickOutside;
 use crate::ui::widget::dynamic_text_input::DynamicTextInput;
+use crate::ui::widget::suggestion_text_input;
+use crate::ui::widget::suggestion_text_input::SuggestionTextInput;
 use crate::ui::{material_icon, material_icon_sized};
 use crate::{DanceInterpreter, Message, Window};
-use iced::advanced::Widget;
-use iced::alignment::Vertical;
+use iced::alignment::{Horizontal, Vertical};
 use iced::border::Radius;
-use iced::widget::scrollable::RelativeOffset;
+use iced::widget::scrollable::{AbsoluteOffset, RelativeOffset, Viewport};
 use iced::widget::{
-    Button, Column, Row, Scrollable, Space, button, checkbox, column as col, radio, row,
-    scrollable, text,
+    Button, Column, Container, Row, Scrollable, Space, button, checkbox, column as col, container,
+    keyed_column, mouse_area, pick_list, progress_bar, radio, row, scrollable, text, text_input,
 };
 use iced::{Alignment, Border, Color, Element, Length, Pixels, Renderer, Size, Theme, window};
+use iced_aw::menu::Item;
 use iced_aw::style::{Status, menu_bar::primary};
 use iced_aw::widget::InnerBounds;
 use iced_aw::{Menu, MenuBar, iced_aw_font, menu, menu_bar, menu_items, quad};
+use std::path::PathBuf;
 use std::sync::LazyLock;
 use std::time::Instant;
 
@@ -29,11 +60,33 @@ pub struct ConfigWindow {
     pub closed: bool,
     pub size: Size,
     pub enable_autoscroll: bool,
+    pub show_live_monitor: bool,
     pub sidebar: Sidebar,
     pub bottombar: Bottombar,
+    pub playlist_filter: PlaylistFilter,
+    pub playlist_columns: PlaylistColumns,
+    pub dance_colors: DanceColors,
+    pub dance_translations: DanceTranslations,
+    pub dance_translations_editor: DanceTranslationsEditor,
+    pub dance_backgrounds: DanceBackgrounds,
+    pub background_cache: BackgroundCache,
+    pub countdown: Countdown,
+    pub layout_editor: LayoutEditor,
+    pub available_templates: Vec<PathBuf>,
+    pub playlist_selection: PlaylistSelection,
+    pub playlist_grid: PlaylistGrid,
+    pub playlist_viewport: Option<Viewport>,
+    pub dance_suggestions: suggestion_text_input::State<String>,
     pub theme: Theme,
+    pub ui_language: LanguageSetting,
 }
 
+/// Height of a single playlist row including its separator.
+/// All rows share this height so only the visible ones have to be built.
+pub const PLAYLIST_ROW_HEIGHT: f32 = 40.0;
+const PLAYLIST_OVERSCAN_ROWS: usize = 5;
+const MONITOR_HEIGHT: f32 = 160.0;
+
 pub static PLAYLIST_SCROLLABLE_ID: LazyLock<iced::widget::Id> =
     LazyLock::new(iced::widget::Id::unique);
 
@@ -45,9 +98,25 @@ impl Window for ConfigWindow {
             size: Size::default(),
 
             enable_autoscroll: true,
+            show_live_monitor: true,
             sidebar: Sidebar::new(),
             bottombar: Bottombar::new(),
+            playlist_filter: PlaylistFilter::new(),
+            playlist_columns: PlaylistColumns::default(),
+            dance_colors: DanceColors::default(),
+            dance_translations: DanceTranslations::default(),
+            dance_translations_editor: DanceTranslationsEditor::default(),
+            dance_backgrounds: DanceBackgrounds::default(),
+            background_cache: BackgroundCache::default(),
+            countdown: Countdown::default(),
+            layout_editor: LayoutEditor::new(),
+            available_templates: SongWindowTemplate::list(),
+            playlist_selection: PlaylistSelection::new(),
+            playlist_grid: PlaylistGrid::new(),
+            playlist_viewport: None,
+            dance_suggestions: suggestion_text_input::State::default(),
             theme: Theme::Dark,
+            ui_language: LanguageSetting::default(),
         }
     }
 
@@ -68,6 +137,12 @@ impl ConfigWindow {
     pub fn view<'a>(&'a self, dance_interpreter: &'a DanceInterpreter) -> Element<'a, Message> {
         let top_bar = self.build_menu_bar(dance_interpreter);
         let playlist_view = self.build_playlist_view(dance_interpreter);
+        let queue_view = self.build_queue_view(dance_interpreter);
+        let monitor_view = self.build_monitor_view(dance_interpreter);
+        let layout_editor = match dance_interpreter.selected_song_window() {
+            Some(song_window) => self.layout_editor.build(&song_window.layouts),
+            None => col!(),
+        };
 
         let side_bar = self
             .sidebar
@@ -82,32 +157,49 @@ impl ConfigWindow {
             .build(dance_interpreter)
             .height(Length::Shrink);
 
-        col![row![col![top_bar, playlist_view], side_bar], bottom_bar]
-            .spacing(5)
-            .into()
+        col![
+            row![
+                col![
+                    top_bar,
+                    monitor_view,
+                    layout_editor,
+                    playlist_view,
+                    queue_view
+                ],
+                side_bar
+            ],
+            bottom_bar
+        ]
+        .spacing(5)
+        .into()
     }
 
-    fn build_playlist_view(&'_ self, dance_interpreter: &DanceInterpreter) -> Column<'_, Message> {
-        let trow: Row<_> = row![
-            text!("#").width(Length::Fixed(24.0)),
-            text!("Title").width(Length::Fill),
-            text!("Artist").width(Length::Fill),
-            text!("Dance").width(Length::Fill),
-            Space::new().width(Length::Fill).height(Length::Shrink),
-            Space::new()
-                .width(Length::Fixed(10.0))
-                .height(Length::Shrink),
-        ]
-        .spacing(5);
+    /// Returns the playlist indices shown in the table, in display order.
+    pub fn visible_playlist_indices(&self, data_provider: &SongDataProvider) -> Vec<usize> {
+        let mut indices = self.playlist_filter.filtered_indices(data_provider);
+        self.playlist_columns
+            .sort_indices(&mut indices, &data_provider.playlist_songs);
+        indices
+    }
 
-        let mut playlist_column: Column<'_, _, _, _> = col!().spacing(5);
+    fn build_playlist_view<'a>(
+        &'a self,
+        dance_interpreter: &'a DanceInterpreter,
+    ) -> Column<'a, Message> {
+        let trow = self.playlist_columns.build_header();
+
+        let visible_indices = self.visible_playlist_indices(&dance_interpreter.data_provider);
+        let (first_row, last_row) = self.visible_row_range(visible_indices.len());
+
+        let mut playlist_rows = keyed_column(vec![]);
+
+        for &i in &visible_indices[first_row..last_row] {
+            let song = &dance_interpreter.data_provider.playlist_songs[i];
+            if song.is_section {
+                playlist_rows = playlist_rows.push(i, self.build_section_row(i, song));
+                continue;
+            }
 
-        for (i, song) in dance_interpreter
-            .data_provider
-            .playlist_songs
-            .iter()
-            .enumerate()
-        {
             let (is_current, is_next, is_traktor, is_played) =
                 dance_interpreter.data_provider.get_play_state(i);
             let icon: Element<Message> = if is_traktor {
@@ -120,6 +212,14 @@ impl ConfigWindow {
                     .into()
             } else if is_next {
                 material_icon("skip_next").width(Length::Fixed(24.0)).into()
+            } else if dance_interpreter
+                .data_provider
+                .get_queue_position(i)
+                .is_some()
+            {
+                material_icon("queue_music")
+                    .width(Length::Fixed(24.0))
+                    .into()
             } else if is_played {
                 material_icon("check").width(Length::Fixed(24.0)).into()
             } else {
@@ -129,56 +229,737 @@ impl ConfigWindow {
                     .into()
             };
 
-            let song_row = row![
-                icon,
-                DynamicTextInput::<'_, Message>::new("Title", &song.title)
-                    .width(Length::Fill)
-                    .on_change(move |v| Message::SongDataEdit(i, SongDataEdit::Title(v))),
-                DynamicTextInput::<'_, Message>::new("Artist", &song.artist)
-                    .width(Length::Fill)
-                    .on_change(move |v| Message::SongDataEdit(i, SongDataEdit::Artist(v))),
-                DynamicTextInput::<'_, Message>::new("Dance", &song.dance)
-                    .width(Length::Fill)
-                    .on_change(move |v| Message::SongDataEdit(i, SongDataEdit::Dance(v))),
-                row![
-                    Space::new().width(Length::Fill).height(Length::Shrink),
-                    material_icon_message_button(
-                        "smart_display",
-                        Message::SongChanged(SongChange::PlaylistAbsolute(i))
-                    ),
-                    material_icon_message_button(
-                        "queue_play_next",
-                        Message::SetNextSong(SongDataSource::Playlist(i))
-                    ),
-                    material_icon_message_button(
-                        "delete",
-                        Message::DeleteSong(SongDataSource::Playlist(i))
-                    ),
-                ]
-                .spacing(5)
-                .width(Length::Fill),
-            ]
-            .spacing(5);
+            let mut song_row: Row<_> = row![mouse_area(icon).on_press(Message::PlaylistSelection(
+                PlaylistSelectionMessage::Click(i)
+            ))];
 
-            if !playlist_column.children().is_empty() {
-                playlist_column = playlist_column.push(separator());
+            for column in self.playlist_columns.visible() {
+                song_row = song_row.push(self.build_playlist_cell(i, column, song));
             }
 
-            playlist_column = playlist_column.push(song_row);
+            let song_row = song_row
+                .push(
+                    row![
+                        Space::new().width(Length::Fill).height(Length::Shrink),
+                        material_icon_message_button(
+                            "smart_display",
+                            Message::SongChanged(SongChange::PlaylistAbsolute(i))
+                        ),
+                        material_icon_message_button(
+                            "queue_play_next",
+                            Message::EnqueueSong(SongDataSource::Playlist(i))
+                        ),
+                        material_icon_message_button(
+                            "delete",
+                            Message::DeleteSong(SongDataSource::Playlist(i))
+                        ),
+                    ]
+                    .spacing(5)
+                    .width(Length::FillPortion(3)),
+                )
+                .spacing(5)
+                .height(Length::Fill)
+                .align_y(Vertical::Center);
+
+            // key rows by their playlist index so widget state follows the song while scrolling
+            let is_selected = self.playlist_selection.is_selected(i);
+            let dance_color = self.dance_colors.color_for(&song.dance);
+            let song_entry = container(col![song_row, separator()])
+                .height(PLAYLIST_ROW_HEIGHT)
+                .style(move |t: &Theme| {
+                    if is_selected {
+                        container::Style::default()
+                            .background(t.extended_palette().primary.weak.color.scale_alpha(0.4))
+                    } else if let Some(color) = dance_color {
+                        container::Style::default()
+                            .background(accent_color(color, t).scale_alpha(0.15))
+                            .border(Border {
+                                color: accent_color(color, t),
+                                width: 1.0,
+                                radius: Radius::new(2.0),
+                            })
+                    } else {
+                        container::Style::default()
+                    }
+                });
+
+            playlist_rows = playlist_rows.push(i, song_entry);
         }
 
+        let playlist_column: Column<'_, _, _, _> = col![
+            Space::new()
+                .width(Length::Fill)
+                .height(first_row as f32 * PLAYLIST_ROW_HEIGHT),
+            playlist_rows,
+            Space::new()
+                .width(Length::Fill)
+                .height((visible_indices.len() - last_row) as f32 * PLAYLIST_ROW_HEIGHT),
+        ];
+
         let playlist_scrollable: Scrollable<'_, Message> = scrollable(playlist_column)
             .width(Length::Fill)
             .height(Length::Fill)
             .spacing(5)
-            .id(PLAYLIST_SCROLLABLE_ID.clone());
+            .id(PLAYLIST_SCROLLABLE_ID.clone())
+            .on_scroll(Message::PlaylistScrolled);
+
+        // clicking elsewhere gives the keyboard back to the song change keys
+        let unfocus = self
+            .playlist_grid
+            .focused
+            .then_some(Message::PlaylistGrid(PlaylistGridMessage::Unfocus));
 
-        col!(trow, playlist_scrollable).spacing(5)
+        col!(
+            self.playlist_filter.build(dance_interpreter),
+            self.playlist_selection.build(),
+            ClickOutside::new(col![trow, playlist_scrollable].spacing(5), unfocus)
+        )
+        .spacing(5)
     }
 
-    fn build_menu_bar<'a>(
+    fn build_section_row<'a>(&'a self, i: usize, section: &'a SongInfo) -> Element<'a, Message> {
+        let is_selected = self.playlist_selection.is_selected(i);
+        let is_cursor = self.playlist_grid.is_cursor(i, PlaylistColumn::Title);
+
+        let title = DynamicTextInput::<'_, Message>::new(tr("Section"), &section.title)
+            .size(18)
+            .width(Length::Fill)
+            .on_change(move |v| Message::SongDataEdit(i, SongDataEdit::Title(v)))
+            .on_enter(Message::PlaylistGrid(PlaylistGridMessage::StartedEditing(
+                i,
+                PlaylistColumn::Title,
+            )))
+            .on_submit(Message::PlaylistGrid(PlaylistGridMessage::Commit))
+            .on_cancel(Message::PlaylistGrid(PlaylistGridMessage::Cancel))
+            .editing(self.playlist_grid.is_editing(i, PlaylistColumn::Title));
+
+        let section_row = row![
+            mouse_area(material_icon("bookmark").width(Length::Fixed(24.0))).on_press(
+                Message::PlaylistSelection(PlaylistSelectionMessage::Click(i))
+            ),
+            container(title)
+                .width(Length::Fill)
+                .style(move |t: &Theme| {
+                    if is_cursor {
+                        container::Style::default().border(Border {
+                            color: t.extended_palette().primary.strong.color,
+                            width: 1.0,
+                            radius: Radius::new(2.0),
+                        })
+                    } else {
+                        container::Style::default()
+                    }
+                }),
+            material_icon_message_button(
+                "delete",
+                Message::DeleteSong(SongDataSource::Playlist(i))
+            ),
+        ]
+        .spacing(5)
+        .height(Length::Fill)
+        .align_y(Vertical::Center);
+
+        container(col![section_row, separator()])
+            .height(PLAYLIST_ROW_HEIGHT)
+            .style(move |t: &Theme| {
+                let palette = t.extended_palette();
+                if is_selected {
+                    container::Style::default()
+                        .background(palette.primary.weak.color.scale_alpha(0.4))
+                } else {
+                    container::Style::default().background(palette.background.weak.color)
+                }
+            })
+            .into()
+    }
+
+    fn build_playlist_cell<'a>(
+        &'a self,
+        i: usize,
+        settings: &ColumnSettings,
+        song: &'a SongInfo,
+    ) -> Element<'a, Message> {
+        let grid_msg = Message::PlaylistGrid;
+        let column = settings.column;
+        let editing = self.playlist_grid.is_editing(i, column);
+
+        let cell: Element<'a, Message> = match column {
+            PlaylistColumn::Dance => SuggestionTextInput::new(
+                &self.dance_suggestions,
+                tr("Dance"),
+                Some(&song.dance),
+                move |v| Message::SongDataEdit(i, SongDataEdit::Dance(v)),
+            )
+            .on_submit(move |v| Message::SongDataEdit(i, SongDataEdit::Dance(v)))
+            .on_open(Message::OpenDanceSuggestions(i))
+            .on_close(Message::CloseDanceSuggestions)
+            .on_cancel(grid_msg(PlaylistGridMessage::Cancel))
+            .editing(editing)
+            .padding(0)
+            .input_style(flat_text_input_style)
+            .width(Length::Fill)
+            .into(),
+            _ => match column.edit(String::new()) {
+                Some(_) => {
+                    DynamicTextInput::<'_, Message>::new(column.label(), &column.value(song))
+                        .width(Length::Fill)
+                        .on_change(move |v| {
+                            column
+                                .edit(v)
+                                .map_or(Message::Noop, |edit| Message::SongDataEdit(i, edit))
+                        })
+                        .on_enter(grid_msg(PlaylistGridMessage::StartedEditing(i, column)))
+                        .on_submit(grid_msg(PlaylistGridMessage::Commit))
+                        .on_cancel(grid_msg(PlaylistGridMessage::Cancel))
+                        .editing(editing)
+                        .into()
+                }
+                None => text(column.value(song))
+                    .wrapping(text::Wrapping::None)
+                    .width(Length::Fill)
+                    .into(),
+            },
+        };
+
+        let is_cursor = self.playlist_grid.is_cursor(i, column);
+
+        container(cell)
+            .width(Length::FillPortion(settings.width))
+            .clip(true)
+            .style(move |t: &Theme| {
+                if is_cursor {
+                    container::Style::default().border(Border {
+                        color: t.extended_palette().primary.strong.color,
+                        width: 1.0,
+                        radius: Radius::new(2.0),
+                    })
+                } else {
+                    container::Style::default()
+                }
+            })
+            .into()
+    }
+
+    /// Returns the scroll offset needed to bring the row at the given position
+    /// of the filtered playlist into view, if it is not fully visible.
+    pub fn playlist_reveal_target(&self, position: usize) -> Option<AbsoluteOffset> {
+        let offset_y = self
+            .playlist_viewport
+            .map(|v| v.absolute_offset().y)
+            .unwrap_or(0.0);
+        let viewport_height = self.playlist_viewport_height();
+
+        let row_top = position as f32 * PLAYLIST_ROW_HEIGHT;
+        let row_bottom = row_top + PLAYLIST_ROW_HEIGHT;
+
+        let y = if row_top < offset_y {
+            row_top
+        } else if row_bottom > offset_y + viewport_height {
+            row_bottom - viewport_height
+        } else {
+            return None;
+        };
+
+        Some(AbsoluteOffset { x: 0.0, y })
+    }
+
+    fn playlist_viewport_height(&self) -> f32 {
+        self.playlist_viewport
+            .map(|v| v.bounds().height)
+            .unwrap_or(self.size.height)
+    }
+
+    /// Returns the range of rows (as positions in the filtered playlist)
+    /// that intersect the current viewport, padded by a few overscan rows.
+    /// The stored offset is clamped to the rows, as it is only updated on the
+    /// next scroll event after a filter shrank the playlist.
+    fn visible_row_range(&self, row_count: usize) -> (usize, usize) {
+        let viewport_height = self.playlist_viewport_height();
+        let max_offset_y = (row_count as f32 * PLAYLIST_ROW_HEIGHT - viewport_height).max(0.0);
+        let offset_y = self
+            .playlist_viewport
+            .map(|v| v.absolute_offset().y)
+            .unwrap_or(0.0)
+            .min(max_offset_y);
+
+        let first = ((offset_y / PLAYLIST_ROW_HEIGHT).floor() as usize)
+            .saturating_sub(PLAYLIST_OVERSCAN_ROWS)
+            .min(row_count);
+        let last = (((offset_y + viewport_height) / PLAYLIST_ROW_HEIGHT).ceil() as usize
+            + PLAYLIST_OVERSCAN_ROWS)
+            .min(row_count);
+
+        (first, last)
+    }
+
+    /// Returns the scroll offset that centers the row at the given position
+    /// of the filtered playlist in the viewport.
+    pub fn playlist_scroll_target(&self, position: usize) -> AbsoluteOffset {
+        let row_center = (position as f32 + 0.5) * PLAYLIST_ROW_HEIGHT;
+
+        AbsoluteOffset {
+            x: 0.0,
+            y: (row_center - self.playlist_viewport_height() / 2.0).max(0.0),
+        }
+    }
+
+    /// Builds the scaled down monitors: the live song window and, in preview
+    /// mode, the staged preview with the action to take it live.
+    fn build_monitor_view<'a>(
+        &self,
+        dance_interpreter: &'a DanceInterpreter,
+    ) -> Container<'a, Message> {
+        let data_provider = &dance_interpreter.data_provider;
+
+        let Some(song_window) = dance_interpreter.selected_song_window() else {
+            return container(col!());
+        };
+        if !self.show_live_monitor && !data_provider.preview_mode {
+            return container(col!());
+        }
+
+        let aspect_ratio = if song_window.size.height > 0.0 {
+            song_window.size.width / song_window.size.height
+        } else {
+            16.0 / 9.0
+        };
+        let size = Size::new(MONITOR_HEIGHT * aspect_ratio, MONITOR_HEIGHT);
+
+        let mut monitors = row![].spacing(10).align_y(Vertical::Bottom);
+
+        if self.show_live_monitor {
+            let live: Element<_> = match data_provider.get_current_song_info() {
+                Some(song_info) => song_window.build_display(
+                    size,
+                    SongDisplay {
+                        song: song_info,
+                        next: data_provider.get_next_song_info(),
+                        upcoming: song_window.transition.upcoming(),
+                        section: data_provider.get_current_section_title(),
+                        playhead: data_provider.get_current_playhead(),
+                    },
+                    self,
+                ),
+                None => Space::new().into(),
+            };
+
+            let mut live = monitor(tr("Live"), live, size);
+            if let Some(playhead) = data_provider.get_current_playhead() {
+                live = live.push(
+                    row![
+                        progress_bar(0.0..=1.0, playhead.progress())
+                            .length(Length::Fill)
+                            .girth(6),
+                        text(format!(
+                            "{} {}",
+                            tr("remaining"),
+                            format_remaining(playhead.remaining())
+                        )),
+                    ]
+                    .spacing(5)
+                    .align_y(Vertical::Center)
+                    .width(size.width),
+                );
+            }
+
+            monitors = monitors.push(live);
+        }
+
+        if data_provider.preview_mode {
+            let preview: Element<_> = match data_provider.get_preview_song_info() {
+                Some(song_info) => song_window.build_display(
+                    size,
+                    SongDisplay {
+                        song: song_info,
+                        next: data_provider.get_preview_next_song_info(),
+                        upcoming: &[],
+                        section: data_provider.get_preview_section_title(),
+                        playhead: None,
+                    },
+                    self,
+                ),
+                None => text(tr("Nothing staged"))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_x(Horizontal::Center)
+                    .align_y(Vertical::Center)
+                    .into(),
+            };
+
+            let take_button = button(text(tr("Take")).align_y(Vertical::Center))
+                .padding([4, 8])
+                .style(button::primary)
+                .on_press_maybe(
+                    data_provider
+                        .preview
+                        .is_some()
+                        .then_some(Message::TakePreview),
+                );
+
+            monitors = monitors.push(monitor(tr("Preview"), preview, size)).push(
+                col![
+                    take_button,
+                    label_message_button_shrink(tr("Clear"), Message::ClearPreview),
+                ]
+                .spacing(5),
+            );
+        }
+
+        container(monitors).padding([5, 0])
+    }
+
+    /// Builds the display window menu: one entry per open display window to
+    /// choose the one the settings below, the monitor and the templates apply to.
+    fn build_song_window_menu<'a>(
+        &'a self,
+        dance_interpreter: &'a DanceInterpreter,
+    ) -> Vec<Element<'a, Message>> {
+        let song_windows = &dance_interpreter.song_windows;
+        let selected = dance_interpreter.selected_song_window();
+
+        let mut items: Vec<Element<_>> = song_windows
+            .iter()
+            .enumerate()
+            .map(|(i, song_window)| {
+                let id = song_window.id;
+
+                checkbox(selected.is_some_and(|w| w.id == id))
+                    .label(format!("{} {}", tr("Song Window"), i + 1))
+                    .on_toggle(move |_| Message::SelectSongWindow(id))
+                    .width(Length::Fill)
+                    .into()
+            })
+            .collect();
+
+        items
+            .push(label_message_button_fill(tr("Open New Window"), Message::OpenSongWindow).into());
+        items.push(label_message_button_fill(tr("Close Window"), Message::CloseSongWindow).into());
+        items.push(separator().into());
+
+        let checked = |f: fn(&SongWindow) -> bool| selected.is_some_and(f);
+        let message = |m: fn(bool) -> Message| selected.is_some().then_some(m);
+
+        let mut fullscreen = checkbox(checked(|w| w.fullscreen))
+            .label(tr("Fullscreen"))
+            .width(Length::Fill);
+        if let Some(id) = selected.map(|w| w.id) {
+            fullscreen = fullscreen.on_toggle(move |state| Message::SetFullscreen(id, state));
+        }
+
+        items.extend([
+            labeled_message_checkbox_opt(
+                tr("Show Thumbnails"),
+                checked(|w| w.enable_image),
+                message(Message::EnableImage),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Show Next Dance"),
+                checked(|w| w.enable_next_dance),
+                message(Message::EnableNextDance),
+            )
+            .into(),
+            row![
+                text(tr("Upcoming Dances")).width(Length::Fill),
+                pick_list(
+                    SongWindow::NEXT_DANCE_COUNTS,
+                    selected.map(|w| w.next_dance_count),
+                    Message::SetNextDanceCount
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Show Block Name"),
+                checked(|w| w.enable_section),
+                message(Message::EnableSection),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Show Clock"),
+                checked(|w| w.enable_clock),
+                message(Message::EnableClock),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Show Countdown"),
+                checked(|w| w.enable_countdown),
+                message(Message::EnableCountdown),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Bilingual Dance Names"),
+                checked(|w| w.enable_translation),
+                message(Message::EnableDanceTranslation),
+            )
+            .into(),
+            row![
+                text(tr("Progress")).width(Length::Fill),
+                pick_list(
+                    ProgressDisplay::ALL,
+                    selected.map(|w| w.progress),
+                    Message::SetProgressDisplay
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            fullscreen.into(),
+            labeled_message_checkbox_opt(
+                tr("Borderless"),
+                checked(|w| w.borderless),
+                message(Message::EnableBorderless),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Always On Top"),
+                checked(|w| w.always_on_top),
+                message(Message::EnableAlwaysOnTop),
+            )
+            .into(),
+            row![
+                text(tr("Transition")).width(Length::Fill),
+                pick_list(
+                    TransitionKind::ALL,
+                    selected.map(|w| w.transition.settings.kind),
+                    Message::SetTransition
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            row![
+                text(tr("Duration (ms)")).width(Length::Fill),
+                pick_list(
+                    TransitionSettings::DURATIONS_MS,
+                    selected.map(|w| w.transition.settings.duration_ms),
+                    Message::SetTransitionDuration
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Text Outline"),
+                checked(|w| w.readability.outline),
+                message(Message::EnableTextOutline),
+            )
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Text Shadow"),
+                checked(|w| w.readability.shadow),
+                message(Message::EnableTextShadow),
+            )
+            .into(),
+            row![
+                text(tr("Safe Area (%)")).width(Length::Fill),
+                pick_list(
+                    Readability::SAFE_AREAS,
+                    selected.map(|w| w.readability.safe_area),
+                    Message::SetSafeArea
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            row![
+                text(tr("Rotation")).width(Length::Fill),
+                pick_list(
+                    Rotation::ALL,
+                    selected.map(|w| w.readability.rotation),
+                    Message::SetRotation
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            labeled_message_checkbox_opt(
+                tr("Mirror Horizontally"),
+                checked(|w| w.readability.mirrored),
+                message(Message::EnableMirroring),
+            )
+            .into(),
+            labeled_message_checkbox(
+                tr("Preview Mode"),
+                dance_interpreter.data_provider.preview_mode,
+                Message::EnablePreviewMode,
+            )
+            .into(),
+            labeled_message_checkbox(
+                tr("Show Live Monitor"),
+                self.show_live_monitor,
+                Message::EnableLiveMonitor,
+            )
+            .into(),
+            label_message_button_fill(
+                tr("Layout Settings"),
+                Message::LayoutEditor(LayoutEditorMessage::Toggle),
+            )
+            .into(),
+        ]);
+
+        items
+    }
+
+    /// Builds the template menu: the fixed layout, every file of the
+    /// template directory and actions to open other files or rescan.
+    fn build_template_menu<'a>(
+        &'a self,
+        dance_interpreter: &'a DanceInterpreter,
+    ) -> Vec<Element<'a, Message>> {
+        let selected = dance_interpreter.selected_song_window();
+        let active = selected.and_then(|w| w.template_path());
+
+        let mut items: Vec<Element<_>> = vec![
+            checkbox(active.is_none())
+                .label(tr("Fixed Layout"))
+                .on_toggle(|_| Message::SelectTemplate(None))
+                .width(Length::Fill)
+                .into(),
+        ];
+
+        items.extend(self.available_templates.iter().map(|path| {
+            let label = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default();
+
+            checkbox(active == Some(path))
+                .label(label)
+                .on_toggle(move |_| Message::SelectTemplate(Some(path.clone())))
+                .width(Length::Fill)
+                .into()
+        }));
+
+        if let Some(error) = selected.and_then(|w| w.template_error.as_ref()) {
+            items.push(
+                text(format!(
+                    "{} {}",
+                    tr("The template could not be loaded:"),
+                    error
+                ))
+                .style(text::danger)
+                .into(),
+            );
+        }
+
+        items.push(
+            label_message_button_fill(tr("Open Template File"), Message::OpenTemplate).into(),
+        );
+        items.push(
+            label_message_button_fill(tr("Reload Templates"), Message::ReloadTemplates).into(),
+        );
+
+        items
+    }
+
+    fn build_language_menu<'a>(
+        &'a self,
+        dance_interpreter: &'a DanceInterpreter,
+    ) -> Vec<Element<'a, Message>> {
+        let selected = dance_interpreter.selected_song_window();
+
+        vec![
+            row![
+                text(tr("Interface")).width(Length::Fill),
+                pick_list(
+                    LanguageSetting::ALL,
+                    Some(self.ui_language),
+                    Message::SetUiLanguage
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+            row![
+                text(tr("Display")).width(Length::Fill),
+                pick_list(
+                    LanguageSetting::ALL,
+                    selected.map(|w| w.language),
+                    Message::SetDisplayLanguage
+                ),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center)
+            .into(),
+        ]
+    }
+
+    fn build_queue_view<'a>(
         &self,
         dance_interpreter: &'a DanceInterpreter,
+    ) -> Container<'a, Message> {
+        let data_provider = &dance_interpreter.data_provider;
+
+        if data_provider.queue.is_empty() {
+            return container(col!());
+        }
+
+        let mut queue_column: Column<'_, _, _, _> = col!().spacing(5);
+
+        for (i, source) in data_provider.queue.iter().enumerate() {
+            let (dance, description) = match source {
+                SongDataSource::Blank => (tr("Blank").to_owned(), String::new()),
+                SongDataSource::Traktor => ("Traktor".to_owned(), String::new()),
+                _ => data_provider
+                    .get_queued_song_info(source)
+                    .map(|song| {
+                        let description = if song.artist.is_empty() {
+                            song.title.clone()
+                        } else {
+                            format!("{} - {}", song.artist, song.title)
+                        };
+                        (song.dance.clone(), description)
+                    })
+                    .unwrap_or_default(),
+            };
+
+            let source_label = match source {
+                SongDataSource::Playlist(index) => format!("#{}", index + 1),
+                SongDataSource::Static(_) => "Static".to_owned(),
+                _ => String::new(),
+            };
+
+            let queue_row = row![
+                text!("{}.", i + 1).width(Length::Fixed(24.0)),
+                text(dance).width(Length::Fill),
+                text(description).width(Length::FillPortion(2)),
+                text(source_label).width(Length::Fixed(48.0)),
+                material_icon_message_button(
+                    "arrow_upward",
+                    Message::QueueEdit(i, QueueEdit::MoveUp)
+                ),
+                material_icon_message_button(
+                    "arrow_downward",
+                    Message::QueueEdit(i, QueueEdit::MoveDown)
+                ),
+                material_icon_message_button("close", Message::QueueEdit(i, QueueEdit::Remove)),
+            ]
+            .spacing(5)
+            .align_y(Vertical::Center);
+
+            queue_column = queue_column.push(queue_row);
+        }
+
+        container(
+            col![
+                separator(),
+                text(tr("Up Next")),
+                scrollable(queue_column)
+                    .width(Length::Fill)
+                    .height(Length::Shrink)
+                    .spacing(5),
+            ]
+            .spacing(5),
+        )
+        .height(Length::Shrink)
+        .max_height(self.size.height / 4.0)
+    }
+
+    fn build_menu_bar<'a>(
+        &'a self,
+        dance_interpreter: &'a DanceInterpreter,
     ) -> MenuBar<'a, Message, Theme, Renderer> {
         let menu_tpl_1 = |items| Menu::new(items).max_width(150.0).offset(15.0).spacing(5.0);
 
@@ -186,34 +967,127 @@ impl ConfigWindow {
         let mb = menu_bar!
         (
             (
-                label_message_button_shrink("File", Message::Noop),
+                label_message_button_shrink(tr("File"), Message::Noop),
                 menu_tpl_1(
                     menu_items!(
-                        (label_message_button_fill("Open Playlist File", Message::OpenPlaylist)),
-                        (label_message_button_fill("Exit", Message::WindowClosed(self.id))),
+                        (label_message_button_fill(tr("Open Playlist File"), Message::OpenPlaylist)),
+                        (label_message_button_fill(tr("Save Playlist File"), Message::SavePlaylist)),
+                        (label_message_button_fill(tr("Exit"), Message::WindowClosed(self.id))),
                     )
                 )
                 .spacing(5.0)
             ),
             (
-                label_message_button_shrink("Edit", Message::Noop),
+                label_message_button_shrink(tr("Edit"), Message::Noop),
                 menu_tpl_1(
                     menu_items!(
-                        (labeled_message_checkbox("Autoscroll", self.enable_autoscroll, Message::EnableAutoscroll)),
-                        (label_message_button_fill("Reload Statics", Message::ReloadStatics)),
-                        (label_message_button_fill("Add blank song", Message::AddBlankSong(RelativeOffset::END))),
+                        (labeled_message_checkbox(tr("Autoscroll"), self.enable_autoscroll, Message::EnableAutoscroll)),
+                        (label_message_button_fill(tr("Reload Statics"), Message::ReloadStatics)),
+                        (label_message_button_fill(tr("Add blank song"), Message::AddBlankSong(RelativeOffset::END))),
+                        (label_message_button_fill(tr("Add section"), Message::PlaylistSelection(PlaylistSelectionMessage::InsertSection))),
                     )
                 )
                 .spacing(5.0)
             ),
             (
-                label_message_button_shrink("SongWindow", Message::Noop),
-                menu_tpl_1(
-                    menu_items!(
-                        (labeled_message_checkbox("Show Thumbnails", dance_interpreter.song_window.enable_image, Message::EnableImage)),
-                        (labeled_message_checkbox("Show Next Dance", dance_interpreter.song_window.enable_next_dance, Message::EnableNextDance)),
-                    )
+                label_message_button_shrink(tr("Columns"), Message::Noop),
+                Menu::new(
+                    self.playlist_columns
+                        .build_chooser()
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(320.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Colours"), Message::Noop),
+                Menu::new(
+                    self.dance_colors
+                        .build_editor()
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(320.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Translations"), Message::Noop),
+                Menu::new(
+                    self.dance_translations_editor
+                        .build(&self.dance_translations)
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(400.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Backgrounds"), Message::Noop),
+                Menu::new(
+                    self.dance_backgrounds
+                        .build_editor()
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(450.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Countdown"), Message::Noop),
+                Menu::new(
+                    self.countdown
+                        .build_editor(&dance_interpreter.data_provider.statics)
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(350.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Templates"), Message::Noop),
+                Menu::new(
+                    self.build_template_menu(dance_interpreter)
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(250.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("Language"), Message::Noop),
+                Menu::new(
+                    self.build_language_menu(dance_interpreter)
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
                 )
+                .max_width(250.0)
+                .offset(15.0)
+                .spacing(5.0)
+            ),
+            (
+                label_message_button_shrink(tr("SongWindow"), Message::Noop),
+                Menu::new(
+                    self.build_song_window_menu(dance_interpreter)
+                        .into_iter()
+                        .map(Item::new)
+                        .collect()
+                )
+                .max_width(200.0)
+                .offset(15.0)
                 .spacing(5.0)
             )
         )
@@ -325,7 +1199,6 @@ fn labeled_message_radio<T: Copy + Eq>(
     //.style(checkbox::secondary)
 }
 
-#[allow(dead_code)]
 fn labeled_message_checkbox_opt(
     label: &'_ str,
     checked: bool,
@@ -358,6 +1231,33 @@ fn labeled_dynamic_text_input<'a>(
     col!(text(label).width(Length::Fill), input,).width(Length::Fill)
 }
 
+/// Text input style that only shows its frame while focused, so it blends into table rows.
+fn flat_text_input_style(theme: &Theme, status: text_input::Status) -> text_input::Style {
+    let style = text_input::default(theme, status);
+
+    match status {
+        text_input::Status::Focused { .. } => style,
+        _ => text_input::Style {
+            background: Color::TRANSPARENT.into(),
+            border: Border::default(),
+            ..style
+        },
+    }
+}
+
+/// Frames a scaled down song display, dark like the song window itself.
+fn monitor<'a>(label: &'a str, display: Element<'a, Message>, size: Size) -> Column<'a, Message> {
+    col![
+        text(label),
+        container(display)
+            .width(size.width)
+            .height(size.height)
+            .clip(true)
+            .style(container::dark),
+    ]
+    .spacing(5)
+}
+
 fn separator() -> quad::Quad {
     quad::Quad {
         quad_color: Color::from([0.5; 3]).into(),
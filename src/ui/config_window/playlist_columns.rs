@@ -0,0 +1,415 @@
+use crate::Message;
+use crate::dataloading::dataprovider::song_data_provider::SongDataEdit;
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::tr;
+use crate::ui::config_window::{label_message_button_shrink, material_icon_message_button};
+use iced::alignment::Vertical;
+use iced::widget::{Row, Space, button, checkbox, row, text};
+use iced::{Element, Length};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PlaylistColumn {
+    TrackNumber,
+    Title,
+    Artist,
+    Dance,
+    Album,
+    Duration,
+    Bpm,
+    Key,
+    FilePath,
+}
+
+impl PlaylistColumn {
+    pub const ALL: [PlaylistColumn; 9] = [
+        PlaylistColumn::TrackNumber,
+        PlaylistColumn::Title,
+        PlaylistColumn::Artist,
+        PlaylistColumn::Dance,
+        PlaylistColumn::Album,
+        PlaylistColumn::Duration,
+        PlaylistColumn::Bpm,
+        PlaylistColumn::Key,
+        PlaylistColumn::FilePath,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PlaylistColumn::TrackNumber => tr("Track"),
+            PlaylistColumn::Title => tr("Title"),
+            PlaylistColumn::Artist => tr("Artist"),
+            PlaylistColumn::Dance => tr("Dance"),
+            PlaylistColumn::Album => tr("Album"),
+            PlaylistColumn::Duration => tr("Duration"),
+            PlaylistColumn::Bpm => tr("BPM"),
+            PlaylistColumn::Key => tr("Key"),
+            PlaylistColumn::FilePath => tr("File"),
+        }
+    }
+
+    pub fn is_editable(self) -> bool {
+        self.edit(String::new()).is_some()
+    }
+
+    /// Returns the text shown for the song in this column.
+    pub fn value(self, song: &SongInfo) -> Cow<'_, str> {
+        match self {
+            PlaylistColumn::TrackNumber if song.track_number == 0 => Cow::Borrowed(""),
+            PlaylistColumn::TrackNumber => Cow::Owned(song.track_number.to_string()),
+            PlaylistColumn::Title => Cow::Borrowed(&song.title),
+            PlaylistColumn::Artist => Cow::Borrowed(&song.artist),
+            PlaylistColumn::Dance => Cow::Borrowed(&song.dance),
+            PlaylistColumn::Album => Cow::Borrowed(&song.album),
+            PlaylistColumn::Duration => song.duration.map_or(Cow::Borrowed(""), |d| {
+                let seconds = d.as_secs();
+                Cow::Owned(format!("{}:{:02}", seconds / 60, seconds % 60))
+            }),
+            PlaylistColumn::Bpm => song
+                .bpm
+                .map_or(Cow::Borrowed(""), |bpm| Cow::Owned(format!("{:.0}", bpm))),
+            PlaylistColumn::Key => Cow::Borrowed(&song.key),
+            PlaylistColumn::FilePath => song
+                .file_path
+                .as_ref()
+                .map_or(Cow::Borrowed(""), |p| p.to_string_lossy()),
+        }
+    }
+
+    /// Returns the edit that sets this column to `value`, if the column is editable.
+    pub fn edit(self, value: String) -> Option<SongDataEdit> {
+        match self {
+            PlaylistColumn::Title => Some(SongDataEdit::Title(value)),
+            PlaylistColumn::Artist => Some(SongDataEdit::Artist(value)),
+            PlaylistColumn::Dance => Some(SongDataEdit::Dance(value)),
+            PlaylistColumn::Album => Some(SongDataEdit::Album(value)),
+            PlaylistColumn::Key => Some(SongDataEdit::Key(value)),
+            _ => None,
+        }
+    }
+
+    pub fn compare(self, a: &SongInfo, b: &SongInfo) -> Ordering {
+        match self {
+            PlaylistColumn::TrackNumber => a.track_number.cmp(&b.track_number),
+            PlaylistColumn::Duration => a.duration.cmp(&b.duration),
+            PlaylistColumn::Bpm => a.bpm.partial_cmp(&b.bpm).unwrap_or(Ordering::Equal),
+            _ => self
+                .value(a)
+                .to_lowercase()
+                .cmp(&self.value(b).to_lowercase()),
+        }
+    }
+
+    fn default_width(self) -> u16 {
+        match self {
+            PlaylistColumn::TrackNumber
+            | PlaylistColumn::Duration
+            | PlaylistColumn::Bpm
+            | PlaylistColumn::Key => 1,
+            _ => 3,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSettings {
+    pub column: PlaylistColumn,
+    pub visible: bool,
+    pub width: u16,
+}
+
+const MAX_COLUMN_WIDTH: u16 = 8;
+
+#[derive(Debug, Clone)]
+pub enum PlaylistColumnsMessage {
+    SetVisible(PlaylistColumn, bool),
+    MoveLeft(PlaylistColumn),
+    MoveRight(PlaylistColumn),
+    Wider(PlaylistColumn),
+    Narrower(PlaylistColumn),
+    Sort(PlaylistColumn),
+    ApplySort,
+}
+
+/// Order, visibility and width of the playlist columns, plus the
+/// sort order of the view, which does not change the playback order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "Vec<ColumnSettings>", into = "Vec<ColumnSettings>")]
+pub struct PlaylistColumns {
+    columns: Vec<ColumnSettings>,
+    /// Sort column and whether it is sorted descending.
+    pub sort: Option<(PlaylistColumn, bool)>,
+}
+
+impl Default for PlaylistColumns {
+    fn default() -> Self {
+        Vec::new().into()
+    }
+}
+
+impl From<Vec<ColumnSettings>> for PlaylistColumns {
+    /// Drops duplicate entries and appends missing columns as hidden,
+    /// so settings written by older versions stay usable.
+    fn from(mut columns: Vec<ColumnSettings>) -> Self {
+        let is_default = columns.is_empty();
+
+        let mut seen = Vec::new();
+        columns.retain(|c| {
+            let is_new = !seen.contains(&c.column);
+            seen.push(c.column);
+            is_new
+        });
+
+        for column in PlaylistColumn::ALL {
+            if !seen.contains(&column) {
+                columns.push(ColumnSettings {
+                    column,
+                    visible: is_default
+                        && matches!(
+                            column,
+                            PlaylistColumn::Title | PlaylistColumn::Artist | PlaylistColumn::Dance
+                        ),
+                    width: column.default_width(),
+                });
+            }
+        }
+
+        for c in columns.iter_mut() {
+            c.width = c.width.clamp(1, MAX_COLUMN_WIDTH);
+        }
+
+        Self {
+            columns,
+            sort: None,
+        }
+    }
+}
+
+impl From<PlaylistColumns> for Vec<ColumnSettings> {
+    fn from(columns: PlaylistColumns) -> Self {
+        columns.columns
+    }
+}
+
+impl PlaylistColumns {
+    pub fn visible(&self) -> impl Iterator<Item = &ColumnSettings> {
+        self.columns.iter().filter(|c| c.visible)
+    }
+
+    /// Returns the visible editable columns in display order.
+    pub fn editable(&self) -> Vec<PlaylistColumn> {
+        self.visible()
+            .map(|c| c.column)
+            .filter(|c| c.is_editable())
+            .collect()
+    }
+
+    /// Applies a change to the column layout. Returns whether the persisted
+    /// part changed. `ApplySort` has to be handled by the caller.
+    pub fn update(&mut self, message: PlaylistColumnsMessage) -> bool {
+        match message {
+            PlaylistColumnsMessage::SetVisible(column, visible) => {
+                self.with_column(column, |c| c.visible = visible)
+            }
+            PlaylistColumnsMessage::MoveLeft(column) => self.move_column(column, -1),
+            PlaylistColumnsMessage::MoveRight(column) => self.move_column(column, 1),
+            PlaylistColumnsMessage::Wider(column) => {
+                self.with_column(column, |c| c.width = (c.width + 1).min(MAX_COLUMN_WIDTH))
+            }
+            PlaylistColumnsMessage::Narrower(column) => {
+                self.with_column(column, |c| c.width = c.width.saturating_sub(1).max(1))
+            }
+            PlaylistColumnsMessage::Sort(column) => {
+                self.sort = match self.sort {
+                    Some((c, false)) if c == column => Some((column, true)),
+                    Some((c, true)) if c == column => None,
+                    _ => Some((column, false)),
+                };
+                false
+            }
+            PlaylistColumnsMessage::ApplySort => false,
+        }
+    }
+
+    fn with_column(&mut self, column: PlaylistColumn, f: impl FnOnce(&mut ColumnSettings)) -> bool {
+        self.columns
+            .iter_mut()
+            .find(|c| c.column == column)
+            .map(f)
+            .is_some()
+    }
+
+    fn move_column(&mut self, column: PlaylistColumn, offset: isize) -> bool {
+        let Some(position) = self.columns.iter().position(|c| c.column == column) else {
+            return false;
+        };
+        let Some(target) = position
+            .checked_add_signed(offset)
+            .filter(|&t| t < self.columns.len())
+        else {
+            return false;
+        };
+
+        self.columns.swap(position, target);
+        true
+    }
+
+    /// Sorts the given playlist indices by the current view sort, if any.
+    /// Songs are only sorted within their section, and the sort is stable,
+    /// so equal songs keep their playlist order.
+    pub fn sort_indices(&self, indices: &mut [usize], songs: &[SongInfo]) {
+        let Some((column, descending)) = self.sort else {
+            return;
+        };
+
+        for block in indices.split_mut(|&i| songs[i].is_section) {
+            block.sort_by(|&a, &b| {
+                let ordering = column.compare(&songs[a], &songs[b]);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+    }
+
+    pub fn build_header(&self) -> Row<'_, Message> {
+        let mut header = row![text!("#").width(Length::Fixed(24.0))].spacing(5);
+
+        for c in self.visible() {
+            let indicator = match self.sort {
+                Some((column, false)) if column == c.column => " ▲",
+                Some((column, true)) if column == c.column => " ▼",
+                _ => "",
+            };
+
+            header = header.push(
+                button(text!("{}{}", c.column.label(), indicator))
+                    .padding(0)
+                    .style(button::text)
+                    .on_press(Message::PlaylistColumns(PlaylistColumnsMessage::Sort(
+                        c.column,
+                    )))
+                    .width(Length::FillPortion(c.width)),
+            );
+        }
+
+        let apply_sort: Element<_> = if self.sort.is_some() {
+            label_message_button_shrink(
+                tr("Apply order"),
+                Message::PlaylistColumns(PlaylistColumnsMessage::ApplySort),
+            )
+            .into()
+        } else {
+            Space::new().width(Length::Shrink).into()
+        };
+
+        header
+            .push(
+                row![
+                    Space::new().width(Length::Fill).height(Length::Shrink),
+                    apply_sort
+                ]
+                .width(Length::FillPortion(3)),
+            )
+            .push(
+                Space::new()
+                    .width(Length::Fixed(10.0))
+                    .height(Length::Shrink),
+            )
+            .align_y(Vertical::Center)
+    }
+
+    /// Builds one row per column for the column chooser menu.
+    pub fn build_chooser(&self) -> Vec<Element<'_, Message>> {
+        let msg = Message::PlaylistColumns;
+
+        self.columns
+            .iter()
+            .map(|c| {
+                let column = c.column;
+
+                row![
+                    checkbox(c.visible)
+                        .label(column.label())
+                        .on_toggle(move |v| msg(PlaylistColumnsMessage::SetVisible(column, v)))
+                        .width(Length::Fill),
+                    material_icon_message_button(
+                        "arrow_upward",
+                        msg(PlaylistColumnsMessage::MoveLeft(column))
+                    ),
+                    material_icon_message_button(
+                        "arrow_downward",
+                        msg(PlaylistColumnsMessage::MoveRight(column))
+                    ),
+                    material_icon_message_button(
+                        "remove",
+                        msg(PlaylistColumnsMessage::Narrower(column))
+                    ),
+                    text!("{}", c.width).width(Length::Fixed(16.0)),
+                    material_icon_message_button("add", msg(PlaylistColumnsMessage::Wider(column))),
+                ]
+                .spacing(5)
+                .align_y(Vertical::Center)
+                .into()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::songinfo::SongInfo;
+    use crate::ui::config_window::playlist_columns::{
+        ColumnSettings, PlaylistColumn, PlaylistColumns, PlaylistColumnsMessage,
+    };
+
+    #[test]
+    fn stored_columns_are_completed_and_deduplicated() {
+        let columns: PlaylistColumns = vec![
+            ColumnSettings {
+                column: PlaylistColumn::Dance,
+                visible: true,
+                width: 20,
+            },
+            ColumnSettings {
+                column: PlaylistColumn::Dance,
+                visible: false,
+                width: 1,
+            },
+        ]
+        .into();
+
+        assert_eq!(columns.columns.len(), PlaylistColumn::ALL.len());
+        assert_eq!(columns.editable(), vec![PlaylistColumn::Dance]);
+        assert_eq!(columns.columns[0].width, 8);
+    }
+
+    #[test]
+    fn view_sort_cycles_and_is_stable() {
+        let songs: Vec<SongInfo> = ["b", "a", "b"]
+            .iter()
+            .map(|t| SongInfo {
+                title: t.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        let mut columns = PlaylistColumns::default();
+
+        columns.update(PlaylistColumnsMessage::Sort(PlaylistColumn::Title));
+        let mut indices = vec![0, 1, 2];
+        columns.sort_indices(&mut indices, &songs);
+        assert_eq!(indices, vec![1, 0, 2]);
+
+        columns.update(PlaylistColumnsMessage::Sort(PlaylistColumn::Title));
+        let mut indices = vec![0, 1, 2];
+        columns.sort_indices(&mut indices, &songs);
+        assert_eq!(indices, vec![0, 2, 1]);
+
+        columns.update(PlaylistColumnsMessage::Sort(PlaylistColumn::Title));
+        assert_eq!(columns.sort, None);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::dataloading::dataprovider::song_data_provider::SongDataProvider;
+use crate::i18n::tr;
+use crate::ui::config_window::{labeled_message_checkbox, material_icon_message_button};
+use crate::ui::widget::suggestion_text_input::{build_matcher, search};
+use crate::{DanceInterpreter, Message};
+use iced::Length;
+use iced::alignment::Vertical;
+use iced::widget::{Row, pick_list, row, text_input};
+use std::fmt::Display;
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub enum PlayedFilter {
+    #[default]
+    All,
+    Played,
+    Unplayed,
+}
+
+impl Display for PlayedFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum PlaylistFilterMessage {
+    SetQuery(String),
+    SetDance(String),
+    SetPlayed(PlayedFilter),
+    SetMissingDance(bool),
+    Reset,
+}
+
+#[derive(Default)]
+pub struct PlaylistFilter {
+    pub query: String,
+    pub dance: Option<String>,
+    pub played: PlayedFilter,
+    pub missing_dance: bool,
+}
+
+impl PlaylistFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, message: PlaylistFilterMessage) {
+        match message {
+            PlaylistFilterMessage::SetQuery(query) => self.query = query,
+            PlaylistFilterMessage::SetDance(dance) => self.dance = Some(dance),
+            PlaylistFilterMessage::SetPlayed(played) => self.played = played,
+            PlaylistFilterMessage::SetMissingDance(missing_dance) => {
+                self.missing_dance = missing_dance
+            }
+            PlaylistFilterMessage::Reset => *self = Self::default(),
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.query.trim().is_empty()
+            || self.dance.is_some()
+            || self.played != PlayedFilter::All
+            || self.missing_dance
+    }
+
+    /// Returns the original playlist indices of all songs matching the filter.
+    /// Section headers are only included while no filter is active.
+    pub fn filtered_indices(&self, data_provider: &SongDataProvider) -> Vec<usize> {
+        let songs = &data_provider.playlist_songs;
+
+        if !self.is_active() {
+            return (0..songs.len()).collect();
+        }
+
+        let candidates = (0..songs.len()).filter(|&i| {
+            let song = &songs[i];
+            if song.is_section {
+                return false;
+            }
+
+            let is_played = data_provider
+                .playlist_played
+                .get(i)
+                .copied()
+                .unwrap_or(false);
+
+            let played_matches = match self.played {
+                PlayedFilter::All => true,
+                PlayedFilter::Played => is_played,
+                PlayedFilter::Unplayed => !is_played,
+            };
+
+            played_matches
+                && (!self.missing_dance || song.dance.trim().is_empty())
+                && self.dance.as_ref().is_none_or(|d| song.dance == *d)
+        });
+
+        if self.query.trim().is_empty() {
+            return candidates.collect();
+        }
+
+        let candidates: Vec<usize> = candidates.collect();
+        let matchers: Vec<String> = candidates
+            .iter()
+            .map(|&i| {
+                let song = &songs[i];
+                build_matcher(format!("{} {} {}", song.title, song.artist, song.dance))
+            })
+            .collect();
+
+        search(candidates, &matchers, &self.query).collect()
+    }
+
+    pub(crate) fn build<'a>(&'a self, dance_interpreter: &'a DanceInterpreter) -> Row<'a, Message> {
+        let mut dances: Vec<String> = dance_interpreter
+            .data_provider
+            .playlist_songs
+            .iter()
+            .map(|s| s.dance.clone())
+            .filter(|d| !d.trim().is_empty())
+            .collect();
+        dances.sort();
+        dances.dedup();
+
+        let played_options = vec![
+            PlayedFilter::All,
+            PlayedFilter::Played,
+            PlayedFilter::Unplayed,
+        ];
+
+        row![
+            text_input(tr("Search title, artist or dance"), &self.query)
+                .on_input(|q| Message::PlaylistFilter(PlaylistFilterMessage::SetQuery(q)))
+                .width(Length::FillPortion(3)),
+            pick_list(dances, self.dance.clone(), |d| {
+                Message::PlaylistFilter(PlaylistFilterMessage::SetDance(d))
+            })
+            .placeholder(tr("All dances"))
+            .width(Length::FillPortion(1)),
+            pick_list(played_options, Some(self.played), |p| {
+                Message::PlaylistFilter(PlaylistFilterMessage::SetPlayed(p))
+            })
+            .width(Length::FillPortion(1)),
+            labeled_message_checkbox(tr("Missing dance"), self.missing_dance, |m| {
+                Message::PlaylistFilter(PlaylistFilterMessage::SetMissingDance(m))
+            })
+            .width(Length::Shrink),
+            material_icon_message_button(
+                "filter_alt_off",
+                Message::PlaylistFilter(PlaylistFilterMessage::Reset)
+            ),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dataprovider::song_data_provider::SongDataProvider;
+    use crate::dataloading::songinfo::SongInfo;
+    use crate::ui::config_window::playlist_filter::{PlayedFilter, PlaylistFilter};
+
+    fn provider() -> SongDataProvider {
+        let mut provider = SongDataProvider::default();
+        provider.set_vec(vec![
+            SongInfo::new(
+                1,
+                "Havana".into(),
+                "Camila Cabello".into(),
+                "Rumba".into(),
+                None,
+            ),
+            SongInfo::new(
+                2,
+                "Perfect".into(),
+                "Ed Sheeran".into(),
+                "Slowfox".into(),
+                None,
+            ),
+            SongInfo::new(
+                3,
+                "Despacito".into(),
+                "Luis Fonsi".into(),
+                "Rumba".into(),
+                None,
+            ),
+            SongInfo::new(4, "Unknown".into(), "Nobody".into(), "".into(), None),
+        ]);
+        provider.playlist_played[0] = true;
+        provider
+    }
+
+    #[test]
+    fn query_matches_title_artist_and_dance() {
+        let provider = provider();
+        let mut filter = PlaylistFilter::new();
+
+        filter.query = "rumba fonsi".to_owned();
+        assert_eq!(filter.filtered_indices(&provider), vec![2]);
+
+        filter.query = "Ed Sheer".to_owned();
+        assert_eq!(filter.filtered_indices(&provider), vec![1]);
+    }
+
+    #[test]
+    fn quick_filters_keep_original_indices() {
+        let provider = provider();
+        let mut filter = PlaylistFilter::new();
+
+        filter.dance = Some("Rumba".to_owned());
+        filter.played = PlayedFilter::Unplayed;
+        assert_eq!(filter.filtered_indices(&provider), vec![2]);
+
+        let filter = PlaylistFilter {
+            missing_dance: true,
+            ..PlaylistFilter::new()
+        };
+        assert_eq!(filter.filtered_indices(&provider), vec![3]);
+    }
+}
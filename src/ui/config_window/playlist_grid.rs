@@ -0,0 +1,173 @@
+use crate::ui::config_window::playlist_columns::PlaylistColumn;
+
+#[derive(Debug, Clone)]
+pub enum PlaylistGridMessage {
+    Unfocus,
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    NextCell,
+    PreviousCell,
+    Edit,
+    StartedEditing(usize, PlaylistColumn),
+    Commit,
+    Cancel,
+}
+
+/// Keyboard focus and cell cursor of the playlist table.
+/// The cursor row is stored as playlist index, so it survives filtering.
+#[derive(Default)]
+pub struct PlaylistGrid {
+    pub focused: bool,
+    pub cursor: Option<(usize, PlaylistColumn)>,
+    pub editing: bool,
+}
+
+impl PlaylistGrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_cursor(&self, playlist_index: usize, column: PlaylistColumn) -> bool {
+        self.focused && self.cursor == Some((playlist_index, column))
+    }
+
+    pub fn is_editing(&self, playlist_index: usize, column: PlaylistColumn) -> bool {
+        self.editing && self.cursor == Some((playlist_index, column))
+    }
+
+    pub fn focus(&mut self, playlist_index: usize, column: PlaylistColumn) {
+        self.focused = true;
+        self.cursor = Some((playlist_index, column));
+    }
+
+    /// Moves the cursor by the given number of rows and columns within
+    /// the visible rows and the editable `columns` in display order.
+    /// Returns the new position in `visible_indices`.
+    pub fn move_cursor(
+        &mut self,
+        rows: isize,
+        columns: isize,
+        visible_indices: &[usize],
+        editable_columns: &[PlaylistColumn],
+    ) -> Option<usize> {
+        let (playlist_index, column) = self.cursor?;
+
+        if visible_indices.is_empty() || editable_columns.is_empty() {
+            return None;
+        }
+
+        let position = visible_indices
+            .iter()
+            .position(|&i| i == playlist_index)
+            .unwrap_or(0) as isize;
+        let new_position = (position + rows).clamp(0, visible_indices.len() as isize - 1);
+        let column_position = editable_columns
+            .iter()
+            .position(|&c| c == column)
+            .unwrap_or(0) as isize;
+        let new_column = usize::try_from(column_position + columns)
+            .ok()
+            .and_then(|c| editable_columns.get(c).copied())
+            .unwrap_or(editable_columns[column_position as usize]);
+
+        let new_index = *visible_indices.get(new_position as usize)?;
+        self.cursor = Some((new_index, new_column));
+
+        Some(new_position as usize)
+    }
+
+    /// Moves the cursor to the next or previous cell, wrapping into the
+    /// adjacent row at the end of a row. The cursor stays on the first and
+    /// last cell of the table. Returns the new position in `visible_indices`.
+    pub fn step_cursor(
+        &mut self,
+        forward: bool,
+        visible_indices: &[usize],
+        editable_columns: &[PlaylistColumn],
+    ) -> Option<usize> {
+        let (index, column) = self.cursor?;
+        let step = if forward { 1 } else { -1 };
+
+        let at_row_end = if forward {
+            editable_columns.last() == Some(&column)
+        } else {
+            editable_columns.first() == Some(&column)
+        };
+
+        if !at_row_end {
+            return self.move_cursor(0, step, visible_indices, editable_columns);
+        }
+
+        let position = self.move_cursor(step, 0, visible_indices, editable_columns)?;
+        let (new_index, _) = self.cursor?;
+        if new_index == index {
+            return None;
+        }
+
+        let column = if forward {
+            editable_columns.first()
+        } else {
+            editable_columns.last()
+        };
+        self.cursor = Some((new_index, *column?));
+        Some(position)
+    }
+
+    /// Gives the keyboard back to the song change keys, keeping the cursor.
+    pub fn unfocus(&mut self) {
+        self.focused = false;
+        self.editing = false;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::config_window::playlist_columns::PlaylistColumn;
+    use crate::ui::config_window::playlist_grid::PlaylistGrid;
+
+    const COLUMNS: [PlaylistColumn; 3] = [
+        PlaylistColumn::Title,
+        PlaylistColumn::Artist,
+        PlaylistColumn::Dance,
+    ];
+
+    #[test]
+    fn cursor_moves_within_visible_rows() {
+        let visible = [1, 4, 6];
+        let mut grid = PlaylistGrid::new();
+        grid.focus(4, PlaylistColumn::Title);
+
+        assert_eq!(grid.move_cursor(1, 1, &visible, &COLUMNS), Some(2));
+        assert_eq!(grid.cursor, Some((6, PlaylistColumn::Artist)));
+
+        assert_eq!(grid.move_cursor(1, 5, &visible, &COLUMNS), Some(2));
+        assert_eq!(grid.cursor, Some((6, PlaylistColumn::Artist)));
+    }
+
+    #[test]
+    fn tab_wraps_into_next_row() {
+        let visible = [0, 1];
+        let mut grid = PlaylistGrid::new();
+        grid.focus(0, PlaylistColumn::Dance);
+
+        grid.step_cursor(true, &visible, &COLUMNS);
+        assert_eq!(grid.cursor, Some((1, PlaylistColumn::Title)));
+
+        grid.step_cursor(false, &visible, &COLUMNS);
+        assert_eq!(grid.cursor, Some((0, PlaylistColumn::Dance)));
+
+        grid.focus(1, PlaylistColumn::Dance);
+        assert_eq!(grid.step_cursor(true, &visible, &COLUMNS), None);
+        assert_eq!(grid.cursor, Some((1, PlaylistColumn::Dance)));
+
+        grid.focus(0, PlaylistColumn::Title);
+        assert_eq!(grid.step_cursor(false, &visible, &COLUMNS), None);
+        assert_eq!(grid.cursor, Some((0, PlaylistColumn::Title)));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::Message;
+use crate::dataloading::dataprovider::song_data_provider::SongDataEdit;
+use crate::i18n::tr;
+use crate::ui::config_window::{label_message_button_shrink, material_icon_message_button};
+use iced::Length;
+use iced::alignment::Vertical;
+use iced::keyboard::Modifiers;
+use iced::widget::{Row, row, text, text_input};
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub enum PlaylistSelectionMessage {
+    Click(usize),
+    SelectAll,
+    Clear,
+    SetBulkDance(String),
+
+    Edit(SongDataEdit),
+    Delete,
+    Enqueue,
+    Export,
+    InsertSection,
+    Copy,
+    Paste,
+    Pasted(Option<String>),
+}
+
+#[derive(Default)]
+pub struct PlaylistSelection {
+    pub selected: BTreeSet<usize>,
+    anchor: Option<usize>,
+    pub modifiers: Modifiers,
+    pub bulk_dance: String,
+}
+
+impl PlaylistSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_selected(&self, playlist_index: usize) -> bool {
+        self.selected.contains(&playlist_index)
+    }
+
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Returns the selected playlist indices in playlist order.
+    pub fn indices(&self) -> Vec<usize> {
+        self.selected.iter().copied().collect()
+    }
+
+    /// Handles a click on a row. `visible_indices` are the playlist indices
+    /// currently shown, in display order, and are used for shift ranges.
+    pub fn click(&mut self, playlist_index: usize, visible_indices: &[usize]) {
+        if self.modifiers.shift()
+            && let Some(anchor) = self.anchor
+            && let Some(from) = visible_indices.iter().position(|&i| i == anchor)
+            && let Some(to) = visible_indices.iter().position(|&i| i == playlist_index)
+        {
+            if !self.modifiers.command() {
+                self.selected.clear();
+            }
+
+            self.selected
+                .extend(&visible_indices[from.min(to)..=from.max(to)]);
+            return;
+        }
+
+        if self.modifiers.command() {
+            if !self.selected.remove(&playlist_index) {
+                self.selected.insert(playlist_index);
+            }
+        } else if self.selected.len() == 1 && self.is_selected(playlist_index) {
+            self.selected.clear();
+        } else {
+            self.selected.clear();
+            self.selected.insert(playlist_index);
+        }
+
+        self.anchor = Some(playlist_index);
+    }
+
+    pub fn select_all(&mut self, visible_indices: &[usize]) {
+        self.selected.extend(visible_indices);
+    }
+
+    pub fn select_range(&mut self, range: Range<usize>) {
+        self.anchor = Some(range.start);
+        self.selected = range.collect();
+    }
+
+    pub(crate) fn build(&self) -> Row<'_, Message> {
+        if self.selected.is_empty() {
+            return row![];
+        }
+
+        let msg = Message::PlaylistSelection;
+
+        row![
+            text!("{} {}", self.selected.len(), tr("selected")).width(Length::Shrink),
+            text_input(tr("Dance"), &self.bulk_dance)
+                .on_input(|d| Message::PlaylistSelection(PlaylistSelectionMessage::SetBulkDance(d)))
+                .on_submit(msg(PlaylistSelectionMessage::Edit(SongDataEdit::Dance(
+                    self.bulk_dance.clone()
+                ))))
+                .width(Length::Fill),
+            label_message_button_shrink(
+                tr("Set Dance"),
+                msg(PlaylistSelectionMessage::Edit(SongDataEdit::Dance(
+                    self.bulk_dance.clone()
+                )))
+            ),
+            label_message_button_shrink(
+                tr("Clear Played"),
+                msg(PlaylistSelectionMessage::Edit(SongDataEdit::Played(false)))
+            ),
+            label_message_button_shrink(tr("Queue"), msg(PlaylistSelectionMessage::Enqueue)),
+            label_message_button_shrink(tr("Export"), msg(PlaylistSelectionMessage::Export)),
+            label_message_button_shrink(
+                tr("Insert Section"),
+                msg(PlaylistSelectionMessage::InsertSection)
+            ),
+            material_icon_message_button("delete", msg(PlaylistSelectionMessage::Delete)),
+            material_icon_message_button("deselect", msg(PlaylistSelectionMessage::Clear)),
+        ]
+        .spacing(5)
+        .align_y(Vertical::Center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::config_window::playlist_selection::PlaylistSelection;
+    use iced::keyboard::Modifiers;
+
+    #[test]
+    fn shift_click_selects_visible_range() {
+        let visible = [0, 2, 3, 5, 8];
+        let mut selection = PlaylistSelection::new();
+
+        selection.click(2, &visible);
+        selection.modifiers = Modifiers::SHIFT;
+        selection.click(5, &visible);
+
+        assert_eq!(selection.indices(), vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn ctrl_click_toggles_rows() {
+        let visible = [0, 1, 2, 3];
+        let mut selection = PlaylistSelection::new();
+
+        selection.click(0, &visible);
+        selection.modifiers = Modifiers::CTRL;
+        selection.click(2, &visible);
+        selection.click(0, &visible);
+
+        assert_eq!(selection.indices(), vec![2]);
+    }
+}
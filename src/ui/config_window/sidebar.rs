@@ -1,4 +1,5 @@
 use crate::dataloading::dataprovider::song_data_provider::SongDataProvider;
+use crate::i18n::tr;
 use crate::traktor_api::{TRAKTOR_SERVER_DEFAULT_ADDR, TraktorNextMode, TraktorSyncMode};
 use crate::ui::config_window::{labeled_message_checkbox, material_icon_sized_message_button};
 use crate::ui::widget::canvas_toggle::CanvasToggle;
@@ -68,7 +69,7 @@ impl Sidebar {
             .padding([0, 4]),
             container(
                 col![
-                    text("Server Settings").size(24),
+                    text(tr("Server Settings")).size(24),
                     row![
                         col![
                             CanvasToggle::new(
@@ -77,7 +78,7 @@ impl Sidebar {
                             )
                             .on_toggle(Message::TraktorEnableServer)
                             .on_draw(power_button::draw),
-                            text("Enable Server")
+                            text(tr("Enable Server"))
                         ]
                         .align_x(Alignment::Center),
                         col![
@@ -87,17 +88,17 @@ impl Sidebar {
                             )
                             .on_toggle(|_| Message::TraktorReconnect)
                             .on_draw(restart_button::draw),
-                            text("Restart Server")
+                            text(tr("Restart Server"))
                         ]
                         .align_x(Alignment::Center)
                     ]
                     .spacing(10),
                     col![
-                        text("Server Address: "),
+                        text(tr("Server Address: ")),
                         self.build_network_interface_combo_box(dance_interpreter)
                     ],
                     labeled_message_checkbox(
-                        "Enable Debug Logging",
+                        tr("Enable Debug Logging"),
                         dance_interpreter
                             .data_provider
                             .traktor_provider
@@ -105,7 +106,7 @@ impl Sidebar {
                         Message::TraktorEnableDebugLogging,
                     ),
                     col![
-                        text("Sync Mode"),
+                        text(tr("Sync Mode")),
                         pick_list(
                             sync_options.clone(),
                             Some(dance_interpreter.data_provider.traktor_provider.sync_mode),
@@ -115,7 +116,7 @@ impl Sidebar {
                     ]
                     .align_x(Alignment::Center),
                     col![
-                        text("Next Song Mode"),
+                        text(tr("Next Song Mode")),
                         pick_list(
                             next_options.clone(),
                             Some(dance_interpreter.data_provider.traktor_provider.next_mode),
@@ -125,7 +126,7 @@ impl Sidebar {
                     ]
                     .align_x(Alignment::Center),
                     col![
-                        text("Next Song Mode (Fallback)"),
+                        text(tr("Next Song Mode (Fallback)")),
                         pick_list(
                             next_options.clone(),
                             Some(
@@ -2,6 +2,7 @@ use iced::widget::Text;
 use iced::widget::text::Shaping;
 use iced::{Font, Length, Pixels, Renderer, Theme};
 
+pub mod color;
 pub mod config_window;
 pub mod song_window;
 pub mod widget;
@@ -0,0 +1,177 @@
+use crate::i18n::tr;
+use crate::ui::color::HexColor;
+use iced::widget::image;
+use iced::{Color, Radians, advanced, gradient};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// Edge length blurred backgrounds are computed at. They are scaled up to
+/// the window size, which blurs them further.
+const BLUR_SIZE: u32 = 48;
+const BLUR_SIGMA: f32 = 2.5;
+/// Brightness of blurred album art, so the texts stay readable on bright covers.
+const BLUR_BRIGHTNESS: f32 = 0.55;
+
+/// Background of the song window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Background {
+    Color(HexColor),
+    /// A vertical gradient from the first colour at the top to the second at the bottom.
+    Gradient(HexColor, HexColor),
+    Image(PathBuf),
+}
+
+impl Background {
+    pub fn kind(&self) -> BackgroundKind {
+        match self {
+            Background::Color(_) => BackgroundKind::Color,
+            Background::Gradient(_, _) => BackgroundKind::Gradient,
+            Background::Image(_) => BackgroundKind::Image,
+        }
+    }
+
+    /// Converts the background to another kind, keeping its colour where possible.
+    /// Returns `None` for [`BackgroundKind::Layout`].
+    pub fn with_kind(current: Option<&Background>, kind: BackgroundKind) -> Option<Background> {
+        let color = match current {
+            Some(Background::Color(color)) | Some(Background::Gradient(color, _)) => color.clone(),
+            _ => HexColor::new([0x2b, 0x2d, 0x31]),
+        };
+
+        match kind {
+            BackgroundKind::Layout => None,
+            BackgroundKind::Color => Some(Background::Color(color)),
+            BackgroundKind::Gradient => Some(Background::Gradient(
+                color,
+                HexColor::new([0x00, 0x00, 0x00]),
+            )),
+            BackgroundKind::Image => Some(match current {
+                Some(Background::Image(path)) => Background::Image(path.clone()),
+                _ => Background::Image(PathBuf::new()),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BackgroundKind {
+    /// The background colour of the layout profile.
+    Layout,
+    Color,
+    Gradient,
+    Image,
+}
+
+impl BackgroundKind {
+    pub const ALL: [BackgroundKind; 4] = [
+        BackgroundKind::Layout,
+        BackgroundKind::Color,
+        BackgroundKind::Gradient,
+        BackgroundKind::Image,
+    ];
+}
+
+impl Display for BackgroundKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            BackgroundKind::Layout => "Layout Colour",
+            BackgroundKind::Color => "Colour",
+            BackgroundKind::Gradient => "Gradient",
+            BackgroundKind::Image => "Image",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+/// A background ready to be drawn, with images already loaded.
+#[derive(Debug, Clone)]
+pub enum BackgroundFill {
+    Color(Color),
+    Gradient(gradient::Linear),
+    Image(image::Handle),
+}
+
+impl BackgroundFill {
+    pub fn gradient(top: Color, bottom: Color) -> Self {
+        BackgroundFill::Gradient(
+            gradient::Linear::new(Radians::PI)
+                .add_stop(0.0, top)
+                .add_stop(1.0, bottom),
+        )
+    }
+}
+
+/// Background images and blurred album art, each decoded once and kept
+/// for as long as the program runs.
+#[derive(Debug, Default)]
+pub struct BackgroundCache {
+    images: RefCell<HashMap<PathBuf, Option<image::Handle>>>,
+    blurred: RefCell<HashMap<advanced::image::Id, Option<image::Handle>>>,
+}
+
+impl BackgroundCache {
+    /// Returns the image file as handle, or `None` if it could not be loaded.
+    pub fn image(&self, path: &Path) -> Option<image::Handle> {
+        self.images
+            .borrow_mut()
+            .entry(path.to_owned())
+            .or_insert_with(|| match ::image::open(path) {
+                Ok(image) => Some(rgba_handle(image.into_rgba8())),
+                Err(e) => {
+                    println!("Failed to load background image {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .clone()
+    }
+
+    /// Forgets the image file, so it is read again the next time it is shown.
+    pub fn forget(&self, path: &Path) {
+        self.images.borrow_mut().remove(path);
+    }
+
+    /// Returns a blurred and darkened version of the album art.
+    pub fn blurred(&self, album_art: &image::Handle) -> Option<image::Handle> {
+        self.blurred
+            .borrow_mut()
+            .entry(album_art.id())
+            .or_insert_with(|| {
+                let decoded = match album_art {
+                    image::Handle::Bytes(_, bytes) => ::image::load_from_memory(bytes),
+                    image::Handle::Path(_, path) => ::image::open(path),
+                    image::Handle::Rgba { .. } => return None,
+                };
+
+                match decoded {
+                    Ok(image) => Some(blur(image)),
+                    Err(e) => {
+                        println!("Failed to decode album art: {}", e);
+                        None
+                    }
+                }
+            })
+            .clone()
+    }
+}
+
+fn blur(image: ::image::DynamicImage) -> image::Handle {
+    let mut blurred = image
+        .thumbnail(BLUR_SIZE, BLUR_SIZE)
+        .blur(BLUR_SIGMA)
+        .into_rgba8();
+
+    for pixel in blurred.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            *channel = (f32::from(*channel) * BLUR_BRIGHTNESS) as u8;
+        }
+    }
+
+    rgba_handle(blurred)
+}
+
+fn rgba_handle(image: ::image::RgbaImage) -> image::Handle {
+    image::Handle::from_rgba(image.width(), image.height(), image.into_raw())
+}
@@ -0,0 +1,510 @@
+use crate::i18n::tr;
+use crate::ui::color::HexColor;
+use iced::alignment::{Horizontal, Vertical};
+use iced::{Font, font};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::sync::{LazyLock, Mutex};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LayoutElementKind {
+    Dance,
+    Title,
+    Artist,
+    NextDance,
+    Section,
+    /// Progress bar and remaining time of the track playing in Traktor.
+    Progress,
+    Clock,
+    Countdown,
+}
+
+impl LayoutElementKind {
+    pub const ALL: [LayoutElementKind; 8] = [
+        LayoutElementKind::Dance,
+        LayoutElementKind::Title,
+        LayoutElementKind::Artist,
+        LayoutElementKind::NextDance,
+        LayoutElementKind::Section,
+        LayoutElementKind::Progress,
+        LayoutElementKind::Clock,
+        LayoutElementKind::Countdown,
+    ];
+
+    /// Whether the element is part of the centered column. The other
+    /// elements are overlays placed in a corner or at an edge.
+    pub fn is_central(self) -> bool {
+        matches!(
+            self,
+            LayoutElementKind::Dance | LayoutElementKind::Title | LayoutElementKind::Artist
+        )
+    }
+}
+
+impl Display for LayoutElementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            LayoutElementKind::Dance => tr("Dance"),
+            LayoutElementKind::Title => tr("Title"),
+            LayoutElementKind::Artist => tr("Artist"),
+            LayoutElementKind::NextDance => tr("Next Dance"),
+            LayoutElementKind::Section => tr("Block Name"),
+            LayoutElementKind::Progress => tr("Progress"),
+            LayoutElementKind::Clock => tr("Clock"),
+            LayoutElementKind::Countdown => tr("Countdown"),
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FontFamily {
+    SansSerif,
+    Serif,
+    Monospace,
+    Named(FontName),
+}
+
+/// Name of a custom font. Fonts are referenced by `'static` names, so the name is
+/// interned when it is loaded or committed in the layout editor, not on every render.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct FontName(&'static str);
+
+impl FontName {
+    pub fn new(name: &str) -> Self {
+        Self(intern_font_name(name))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl Serialize for FontName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FontName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| FontName::new(&name))
+    }
+}
+
+impl Display for FontFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontFamily::SansSerif => f.write_str(tr("Sans Serif")),
+            FontFamily::Serif => f.write_str(tr("Serif")),
+            FontFamily::Monospace => f.write_str(tr("Monospace")),
+            FontFamily::Named(_) => f.write_str(tr("Custom")),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FontWeight {
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    Black,
+}
+
+impl FontWeight {
+    pub const ALL: [FontWeight; 6] = [
+        FontWeight::Light,
+        FontWeight::Normal,
+        FontWeight::Medium,
+        FontWeight::Semibold,
+        FontWeight::Bold,
+        FontWeight::Black,
+    ];
+}
+
+impl Display for FontWeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            FontWeight::Light => "Light",
+            FontWeight::Normal => "Normal",
+            FontWeight::Medium => "Medium",
+            FontWeight::Semibold => "Semibold",
+            FontWeight::Bold => "Bold",
+            FontWeight::Black => "Black",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+impl From<FontWeight> for font::Weight {
+    fn from(weight: FontWeight) -> Self {
+        match weight {
+            FontWeight::Light => font::Weight::Light,
+            FontWeight::Normal => font::Weight::Normal,
+            FontWeight::Medium => font::Weight::Medium,
+            FontWeight::Semibold => font::Weight::Semibold,
+            FontWeight::Bold => font::Weight::Bold,
+            FontWeight::Black => font::Weight::Black,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum HorizontalAlign {
+    Left,
+    Center,
+    Right,
+}
+
+impl HorizontalAlign {
+    pub const ALL: [HorizontalAlign; 3] = [
+        HorizontalAlign::Left,
+        HorizontalAlign::Center,
+        HorizontalAlign::Right,
+    ];
+}
+
+impl Display for HorizontalAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            HorizontalAlign::Left => "Left",
+            HorizontalAlign::Center => "Center",
+            HorizontalAlign::Right => "Right",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+impl From<HorizontalAlign> for Horizontal {
+    fn from(align: HorizontalAlign) -> Self {
+        match align {
+            HorizontalAlign::Left => Horizontal::Left,
+            HorizontalAlign::Center => Horizontal::Center,
+            HorizontalAlign::Right => Horizontal::Right,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum VerticalAlign {
+    Top,
+    Bottom,
+}
+
+impl VerticalAlign {
+    pub const ALL: [VerticalAlign; 2] = [VerticalAlign::Top, VerticalAlign::Bottom];
+}
+
+impl Display for VerticalAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            VerticalAlign::Top => "Top",
+            VerticalAlign::Bottom => "Bottom",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+impl From<VerticalAlign> for Vertical {
+    fn from(align: VerticalAlign) -> Self {
+        match align {
+            VerticalAlign::Top => Vertical::Top,
+            VerticalAlign::Bottom => Vertical::Bottom,
+        }
+    }
+}
+
+/// What happens to texts that are too wide even at their minimum size.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TextOverflow {
+    #[default]
+    Ellipsis,
+    Marquee,
+}
+
+impl TextOverflow {
+    pub const ALL: [TextOverflow; 2] = [TextOverflow::Ellipsis, TextOverflow::Marquee];
+}
+
+impl Display for TextOverflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            TextOverflow::Ellipsis => "Ellipsis",
+            TextOverflow::Marquee => "Marquee",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+/// Appearance of a single text element of the song window.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElementStyle {
+    pub kind: LayoutElementKind,
+    pub family: FontFamily,
+    pub weight: FontWeight,
+    /// Font size in percent of the window height.
+    pub size: f32,
+    pub color: HexColor,
+    pub align: HorizontalAlign,
+    /// Only used by overlay elements.
+    pub vertical: VerticalAlign,
+    /// Long texts shrink down to this size, in percent of `size`.
+    #[serde(default = "default_min_scale")]
+    pub min_scale: f32,
+    #[serde(default)]
+    pub overflow: TextOverflow,
+}
+
+fn default_min_scale() -> f32 {
+    60.0
+}
+
+impl ElementStyle {
+    pub fn default_for(kind: LayoutElementKind) -> Self {
+        let (size, align, vertical) = match kind {
+            LayoutElementKind::Dance => (100.0 / 8.0, HorizontalAlign::Center, VerticalAlign::Top),
+            LayoutElementKind::Title => (100.0 / 20.0, HorizontalAlign::Center, VerticalAlign::Top),
+            LayoutElementKind::Artist => {
+                (100.0 / 25.0, HorizontalAlign::Center, VerticalAlign::Top)
+            }
+            LayoutElementKind::NextDance => {
+                (100.0 / 25.0, HorizontalAlign::Right, VerticalAlign::Bottom)
+            }
+            LayoutElementKind::Section => (100.0 / 30.0, HorizontalAlign::Left, VerticalAlign::Top),
+            LayoutElementKind::Progress => {
+                (100.0 / 30.0, HorizontalAlign::Center, VerticalAlign::Bottom)
+            }
+            LayoutElementKind::Clock => (100.0 / 25.0, HorizontalAlign::Right, VerticalAlign::Top),
+            LayoutElementKind::Countdown => {
+                (100.0 / 15.0, HorizontalAlign::Left, VerticalAlign::Bottom)
+            }
+        };
+
+        Self {
+            kind,
+            family: FontFamily::SansSerif,
+            weight: FontWeight::Normal,
+            size,
+            color: HexColor::new([0xff, 0xff, 0xff]),
+            align,
+            vertical,
+            min_scale: default_min_scale(),
+            overflow: TextOverflow::default(),
+        }
+    }
+
+    pub fn font(&self) -> Font {
+        let family = match &self.family {
+            FontFamily::SansSerif => font::Family::SansSerif,
+            FontFamily::Serif => font::Family::Serif,
+            FontFamily::Monospace => font::Family::Monospace,
+            FontFamily::Named(name) => font::Family::Name(name.as_str()),
+        };
+
+        Font {
+            family,
+            weight: self.weight.into(),
+            ..Font::DEFAULT
+        }
+    }
+}
+
+/// Leaks each distinct name once, so committing the same name again reuses it.
+fn intern_font_name(name: &str) -> &'static str {
+    static NAMES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+    let mut names = NAMES.lock().unwrap();
+    if let Some(interned) = names.iter().find(|&&n| n == name) {
+        return interned;
+    }
+
+    let interned: &'static str = Box::leak(name.to_owned().into_boxed_str());
+    names.push(interned);
+    interned
+}
+
+/// Layout of the song window. The order of `elements` is the order of the
+/// central elements from top to bottom.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SongWindowLayout {
+    pub background: HexColor,
+    pub elements: Vec<ElementStyle>,
+}
+
+impl Default for SongWindowLayout {
+    fn default() -> Self {
+        Self {
+            background: HexColor::new([0x2b, 0x2d, 0x31]),
+            elements: LayoutElementKind::ALL
+                .into_iter()
+                .map(ElementStyle::default_for)
+                .collect(),
+        }
+    }
+}
+
+impl SongWindowLayout {
+    pub fn element(&self, kind: LayoutElementKind) -> ElementStyle {
+        self.elements
+            .iter()
+            .find(|e| e.kind == kind)
+            .cloned()
+            .unwrap_or_else(|| ElementStyle::default_for(kind))
+    }
+
+    pub fn element_mut(&mut self, kind: LayoutElementKind) -> &mut ElementStyle {
+        let position = match self.elements.iter().position(|e| e.kind == kind) {
+            Some(position) => position,
+            None => {
+                self.elements.push(ElementStyle::default_for(kind));
+                self.elements.len() - 1
+            }
+        };
+
+        &mut self.elements[position]
+    }
+
+    /// Returns the central elements in display order.
+    pub fn central_elements(&self) -> Vec<ElementStyle> {
+        let mut kinds: Vec<LayoutElementKind> = self
+            .elements
+            .iter()
+            .map(|e| e.kind)
+            .filter(|k| k.is_central())
+            .collect();
+
+        for kind in LayoutElementKind::ALL {
+            if kind.is_central() && !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+
+        kinds.into_iter().map(|k| self.element(k)).collect()
+    }
+
+    /// Moves a central element up or down in the display order.
+    pub fn move_element(&mut self, kind: LayoutElementKind, offset: isize) {
+        // materialize missing elements so positions are stable
+        for k in LayoutElementKind::ALL {
+            self.element_mut(k);
+        }
+
+        let central: Vec<usize> = (0..self.elements.len())
+            .filter(|&i| self.elements[i].kind.is_central())
+            .collect();
+        let Some(position) = central.iter().position(|&i| self.elements[i].kind == kind) else {
+            return;
+        };
+        let Some(&target) = position
+            .checked_add_signed(offset)
+            .and_then(|t| central.get(t))
+        else {
+            return;
+        };
+
+        self.elements.swap(central[position], target);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutProfile {
+    pub name: String,
+    pub layout: SongWindowLayout,
+}
+
+/// Named song window layouts, e.g. one per venue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutProfiles {
+    pub active: usize,
+    pub profiles: Vec<LayoutProfile>,
+}
+
+impl Default for LayoutProfiles {
+    fn default() -> Self {
+        Self {
+            active: 0,
+            profiles: vec![LayoutProfile {
+                name: "Default".to_owned(),
+                layout: SongWindowLayout::default(),
+            }],
+        }
+    }
+}
+
+impl LayoutProfiles {
+    pub fn active(&self) -> &SongWindowLayout {
+        static DEFAULT: LazyLock<SongWindowLayout> = LazyLock::new(SongWindowLayout::default);
+
+        self.profiles
+            .get(self.active)
+            .map_or(&DEFAULT, |p| &p.layout)
+    }
+
+    pub fn active_mut(&mut self) -> &mut SongWindowLayout {
+        if self.profiles.is_empty() {
+            *self = Self::default();
+        }
+        self.active = self.active.min(self.profiles.len() - 1);
+
+        &mut self.profiles[self.active].layout
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.profiles.iter().map(|p| p.name.clone()).collect()
+    }
+
+    pub fn active_name(&self) -> Option<String> {
+        self.profiles.get(self.active).map(|p| p.name.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::song_window::layout::{
+        FontFamily, FontName, LayoutElementKind, SongWindowLayout,
+    };
+
+    #[test]
+    fn central_elements_can_be_reordered() {
+        let mut layout = SongWindowLayout {
+            elements: Vec::new(),
+            ..Default::default()
+        };
+
+        layout.move_element(LayoutElementKind::Artist, -1);
+        layout.move_element(LayoutElementKind::Dance, 5);
+
+        let order: Vec<LayoutElementKind> =
+            layout.central_elements().iter().map(|e| e.kind).collect();
+        assert_eq!(
+            order,
+            vec![
+                LayoutElementKind::Dance,
+                LayoutElementKind::Artist,
+                LayoutElementKind::Title
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_font_names_are_interned_once() {
+        let family: FontFamily = serde_json::from_str(r#"{"Named":"Futura"}"#).unwrap();
+        let FontFamily::Named(name) = family else {
+            panic!("expected a custom font");
+        };
+
+        assert_eq!(name.as_str(), "Futura");
+        assert!(std::ptr::eq(
+            name.as_str(),
+            FontName::new("Futura").as_str()
+        ));
+        assert_eq!(
+            serde_json::to_string(&FontFamily::Named(name)).unwrap(),
+            r#"{"Named":"Futura"}"#
+        );
+    }
+}
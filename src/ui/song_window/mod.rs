@@ -0,0 +1,902 @@
+pub mod background;
+pub mod layout;
+pub mod placement;
+pub mod progress;
+pub mod readability;
+pub mod template;
+pub mod transition;
+
+use crate::Window;
+use crate::dataloading::dance_translations::DanceTranslations;
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::LanguageSetting;
+use crate::traktor_api::Playhead;
+use crate::ui::config_window::ConfigWindow;
+use crate::ui::config_window::countdown::RunningCountdown;
+use crate::ui::song_window::background::BackgroundFill;
+use crate::ui::song_window::layout::{
+    ElementStyle, LayoutElementKind, LayoutProfiles, SongWindowLayout, TextOverflow,
+};
+use crate::ui::song_window::placement::WindowPlacement;
+use crate::ui::song_window::progress::{ProgressDisplay, format_remaining};
+use crate::ui::song_window::readability::Readability;
+use crate::ui::song_window::template::{SongWindowTemplate, TemplateContext};
+use crate::ui::song_window::transition::{Transition, TransitionKind, TransitionSettings};
+use crate::ui::widget::fit_text::FitText;
+use crate::ui::widget::projection::{FillRun, ImageRun, Projected, ProjectedImage, Projection};
+use crate::ui::widget::translate::Translate;
+use crate::{DanceInterpreter, Message};
+use iced::widget::space::vertical;
+use iced::widget::text::LineHeight;
+use iced::widget::{Space, column, container, image, row, stack};
+use iced::{Color, ContentFit, Gradient, Padding, Point, Size, Vector};
+use iced::{Element, Length, Renderer, window};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// The song a song window shows and what comes after it.
+#[derive(Debug, Copy, Clone)]
+pub struct SongDisplay<'a> {
+    pub song: &'a SongInfo,
+    pub next: Option<&'a SongInfo>,
+    /// Dances of the next songs, consecutive identical dances collapsed.
+    pub upcoming: &'a [String],
+    pub section: Option<&'a str>,
+    /// Set while Traktor plays the song on air.
+    pub playhead: Option<Playhead>,
+}
+
+pub struct SongWindow {
+    pub id: window::Id,
+    pub closed: bool,
+    pub size: Size,
+
+    pub enable_image: bool,
+    pub enable_next_dance: bool,
+    /// How many upcoming dances are listed, one shows just the next dance.
+    pub next_dance_count: usize,
+    pub enable_section: bool,
+    /// Shows the translated dance names below the dances.
+    pub enable_translation: bool,
+    pub enable_clock: bool,
+    pub enable_countdown: bool,
+    /// Language of the labels shown to the audience.
+    pub language: LanguageSetting,
+    pub readability: Readability,
+    pub progress: ProgressDisplay,
+
+    pub fullscreen: bool,
+    pub borderless: bool,
+    pub always_on_top: bool,
+    position: Option<Point>,
+    windowed_size: Option<Size>,
+    monitor_size: Option<Size>,
+    /// Placement restored at startup, checked once the monitor of the window is known.
+    pending_placement: Option<WindowPlacement>,
+
+    pub layouts: LayoutProfiles,
+    /// Template file selected to replace the fixed layout, kept in the
+    /// settings even while it cannot be loaded.
+    template_path: Option<PathBuf>,
+    /// Last version of the selected template that loaded, replaces the fixed layout while set.
+    pub template: Option<SongWindowTemplate>,
+    /// Why the selected template file could not be loaded.
+    pub template_error: Option<String>,
+    pub transition: Transition,
+}
+
+impl Window for SongWindow {
+    fn new(id: window::Id) -> Self {
+        Self {
+            id,
+            closed: false,
+            size: Size::default(),
+
+            enable_image: true,
+            enable_next_dance: true,
+            next_dance_count: 1,
+            enable_section: true,
+            enable_translation: false,
+            enable_clock: false,
+            enable_countdown: true,
+            language: LanguageSetting::default(),
+            readability: Readability::default(),
+            progress: ProgressDisplay::default(),
+
+            fullscreen: false,
+            borderless: false,
+            always_on_top: false,
+            position: None,
+            windowed_size: None,
+            monitor_size: None,
+            pending_placement: None,
+
+            layouts: LayoutProfiles::default(),
+            template_path: None,
+            template: None,
+            template_error: None,
+            transition: Transition::new(TransitionSettings::default()),
+        }
+    }
+
+    fn on_resize(&mut self, size: Size) {
+        self.size = size;
+
+        if !self.fullscreen {
+            self.windowed_size = Some(size);
+        }
+    }
+
+    fn on_close(&mut self) {
+        self.closed = true;
+    }
+
+    fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// Settings of a display window that are kept across restarts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SongWindowSettings {
+    pub enable_image: bool,
+    pub enable_next_dance: bool,
+    pub next_dance_count: usize,
+    pub enable_section: bool,
+    pub enable_translation: bool,
+    pub enable_clock: bool,
+    pub enable_countdown: bool,
+    pub language: LanguageSetting,
+    pub readability: Readability,
+    pub progress: ProgressDisplay,
+    pub placement: WindowPlacement,
+    pub borderless: bool,
+    pub always_on_top: bool,
+    pub layouts: LayoutProfiles,
+    pub template: Option<PathBuf>,
+    pub transition: TransitionSettings,
+}
+
+impl Default for SongWindowSettings {
+    fn default() -> Self {
+        Self {
+            enable_image: true,
+            enable_next_dance: true,
+            next_dance_count: 1,
+            enable_section: true,
+            enable_translation: false,
+            enable_clock: false,
+            enable_countdown: true,
+            language: LanguageSetting::default(),
+            readability: Readability::default(),
+            progress: ProgressDisplay::default(),
+            placement: WindowPlacement::default(),
+            borderless: false,
+            always_on_top: false,
+            layouts: LayoutProfiles::default(),
+            template: None,
+            transition: TransitionSettings::default(),
+        }
+    }
+}
+
+impl SongWindow {
+    pub const NEXT_DANCE_COUNTS: [usize; 5] = [1, 2, 3, 4, 5];
+
+    pub fn settings(&self) -> SongWindowSettings {
+        SongWindowSettings {
+            enable_image: self.enable_image,
+            enable_next_dance: self.enable_next_dance,
+            next_dance_count: self.next_dance_count,
+            enable_section: self.enable_section,
+            enable_translation: self.enable_translation,
+            enable_clock: self.enable_clock,
+            enable_countdown: self.enable_countdown,
+            language: self.language,
+            readability: self.readability.clone(),
+            progress: self.progress,
+            placement: WindowPlacement {
+                position: self.position.map(|p| [p.x, p.y]),
+                size: self.windowed_size.map(|s| [s.width, s.height]),
+                fullscreen: self.fullscreen,
+                monitor_size: self.monitor_size.map(|s| [s.width, s.height]),
+            },
+            borderless: self.borderless,
+            always_on_top: self.always_on_top,
+            layouts: self.layouts.clone(),
+            template: self.template_path().cloned(),
+            transition: self.transition.settings.clone(),
+        }
+    }
+
+    pub fn apply_settings(&mut self, settings: SongWindowSettings) {
+        self.enable_image = settings.enable_image;
+        self.enable_next_dance = settings.enable_next_dance;
+        self.next_dance_count = settings.next_dance_count.max(1);
+        self.enable_section = settings.enable_section;
+        self.enable_translation = settings.enable_translation;
+        self.enable_clock = settings.enable_clock;
+        self.enable_countdown = settings.enable_countdown;
+        self.language = settings.language;
+        self.readability = settings.readability;
+        self.progress = settings.progress;
+        self.borderless = settings.borderless;
+        self.always_on_top = settings.always_on_top;
+        self.layouts = settings.layouts;
+        if let Err(e) = self.set_template(settings.template) {
+            println!("Failed to load template: {}", e);
+        }
+        self.transition.settings = settings.transition;
+
+        let placement = settings.placement;
+        self.fullscreen = placement.fullscreen;
+        self.position = placement.position.map(|[x, y]| Point::new(x, y));
+        self.windowed_size = placement
+            .size
+            .map(|[width, height]| Size::new(width, height));
+        self.monitor_size = placement
+            .monitor_size
+            .map(|[width, height]| Size::new(width, height));
+        self.pending_placement = Some(placement);
+    }
+
+    pub fn on_move(&mut self, position: Point) {
+        if !self.fullscreen {
+            self.position = Some(position);
+        }
+    }
+
+    /// Records the size of the monitor the window is on. Returns whether the
+    /// window was restored onto a monitor that is no longer connected and has
+    /// to be moved back into view.
+    pub fn on_monitor(&mut self, monitor_size: Option<Size>) -> bool {
+        self.monitor_size = monitor_size;
+
+        let Some(placement) = self.pending_placement.take() else {
+            return false;
+        };
+        if placement.is_on_saved_monitor(monitor_size) {
+            return false;
+        }
+
+        self.fullscreen = false;
+        self.position = None;
+        true
+    }
+
+    /// Selects and loads the template file, or returns to the fixed layout if
+    /// no path is given. A template that fails to load stays selected, and
+    /// when it is reloaded, its last version that loaded stays on screen.
+    pub fn set_template(&mut self, path: Option<PathBuf>) -> std::io::Result<()> {
+        let loaded = path
+            .as_deref()
+            .map(|path| {
+                SongWindowTemplate::load(path).map_err(|e| {
+                    std::io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+                })
+            })
+            .transpose();
+        let reloading = self.template_path == path;
+        self.template_path = path;
+
+        match loaded {
+            Ok(template) => {
+                self.template = template;
+                self.template_error = None;
+                Ok(())
+            }
+            Err(e) => {
+                if !reloading {
+                    self.template = None;
+                }
+                self.template_error = Some(e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    pub fn template_path(&self) -> Option<&PathBuf> {
+        self.template_path.as_ref()
+    }
+
+    pub fn view<'a>(&'a self, state: &'a DanceInterpreter) -> Element<'a, Message> {
+        let data_provider = &state.data_provider;
+        let layout = self.layouts.active();
+        let config = &state.config_window;
+        let translations = &config.dance_translations;
+        let (content_size, padding) = self.readability.content_area(self.size);
+        let current_song = data_provider.get_current_song_info();
+
+        let current = |opacity| {
+            let display = current_song.map(|song| SongDisplay {
+                song,
+                next: data_provider.get_next_song_info(),
+                upcoming: self.transition.upcoming(),
+                section: data_provider.get_current_section_title(),
+                playhead: data_provider.get_current_playhead(),
+            });
+
+            self.project(
+                self.build_content(content_size, display, translations, opacity),
+                padding,
+            )
+        };
+
+        let clock = self.project(
+            self.build_clock(content_size, config.countdown.running.as_ref()),
+            padding,
+        );
+
+        let Some((previous_song, t)) = self.transition.progress(Instant::now()) else {
+            return with_background(
+                stack![
+                    self.build_background(current_song, config, 1.0),
+                    current(1.0),
+                    clock,
+                ]
+                .into(),
+                layout,
+            );
+        };
+        let previous = |opacity| {
+            self.project(
+                self.build_content(content_size, previous_song.display(), translations, opacity),
+                padding,
+            )
+        };
+
+        let content = match self.transition.settings.kind {
+            TransitionKind::Slide => stack![
+                Translate::new(previous(1.0), Vector::new(-t, 0.0)),
+                Translate::new(current(1.0), Vector::new(1.0 - t, 0.0)),
+            ],
+            TransitionKind::FadeThroughBlack => {
+                let (content, darkness) = if t < 0.5 {
+                    (previous(1.0 - 2.0 * t), 2.0 * t)
+                } else {
+                    (current(2.0 * t - 1.0), 2.0 - 2.0 * t)
+                };
+
+                stack![
+                    content,
+                    container(Space::new())
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .style(move |_| {
+                            container::Style::default()
+                                .background(Color::from_rgba(0.0, 0.0, 0.0, darkness))
+                        }),
+                ]
+            }
+            TransitionKind::Crossfade | TransitionKind::None => {
+                stack![previous(1.0 - t), current(t)]
+            }
+        };
+
+        // the backgrounds crossfade, whatever the transition of the content
+        with_background(
+            stack![
+                self.build_background(previous_song.song.as_ref(), config, 1.0),
+                self.build_background(current_song, config, t),
+                content.width(Length::Fill).height(Length::Fill),
+                clock,
+            ]
+            .into(),
+            layout,
+        )
+    }
+
+    /// Renders the song display for the given window size, so it can also be
+    /// used for scaled down previews in the config window.
+    pub fn build_display<'a>(
+        &self,
+        size: Size,
+        display: SongDisplay<'a>,
+        config: &ConfigWindow,
+    ) -> Element<'a, Message> {
+        let (content_size, padding) = self.readability.content_area(size);
+        let content =
+            self.build_content(content_size, Some(display), &config.dance_translations, 1.0);
+
+        let clock = self.build_clock(content_size, config.countdown.running.as_ref());
+
+        with_background(
+            stack![
+                self.build_background(Some(display.song), config, 1.0),
+                self.project(content, padding),
+                self.project(clock, padding),
+            ]
+            .into(),
+            self.layouts.active(),
+        )
+    }
+
+    /// Builds the background of the song as set for its dance, filling the
+    /// whole window regardless of the rotation and the safe area.
+    fn build_background<'a>(
+        &self,
+        song: Option<&SongInfo>,
+        config: &ConfigWindow,
+        opacity: f32,
+    ) -> Element<'a, Message> {
+        let fill = config
+            .dance_backgrounds
+            .fill_for(song, &config.background_cache);
+
+        let background: iced::Background = match fill {
+            Some(BackgroundFill::Image(handle)) => {
+                return image(handle)
+                    .content_fit(ContentFit::Cover)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .opacity(opacity)
+                    .into();
+            }
+            Some(BackgroundFill::Gradient(gradient)) => {
+                Gradient::Linear(gradient).scale_alpha(opacity).into()
+            }
+            Some(BackgroundFill::Color(color)) => color.scale_alpha(opacity).into(),
+            None => self
+                .layouts
+                .active()
+                .background
+                .color()
+                .scale_alpha(opacity)
+                .into(),
+        };
+
+        container(Space::new())
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .style(move |_| container::Style::default().background(background))
+            .into()
+    }
+
+    /// Keeps the content inside the safe area and turns and mirrors it as set.
+    fn project<'a>(&self, content: Element<'a, Message>, padding: Padding) -> Element<'a, Message> {
+        Projection::new(
+            container(content).padding(padding),
+            self.readability.rotation.quarter_turns(),
+            self.readability.mirrored,
+        )
+        .into()
+    }
+
+    /// Renders the song without the background. The opacity is applied to
+    /// the text and the cover, so transitions can fade between two songs.
+    fn build_content<'a>(
+        &self,
+        size: Size,
+        display: Option<SongDisplay<'a>>,
+        translations: &DanceTranslations,
+        opacity: f32,
+    ) -> Element<'a, Message> {
+        let layout = self.layouts.active();
+        let translations = self.enable_translation.then_some(translations);
+        let translate = |dance: &str| translations.and_then(|t| t.translate(dance));
+
+        let Some(SongDisplay {
+            song: song_info,
+            next: next_song_info,
+            upcoming,
+            section,
+            playhead,
+        }) = display
+        else {
+            return Space::new().width(Length::Fill).height(Length::Fill).into();
+        };
+        let upcoming = &upcoming[..upcoming.len().min(self.next_dance_count)];
+
+        if let Some(template) = &self.template {
+            let context = TemplateContext {
+                song: song_info,
+                next: next_song_info,
+                upcoming,
+                section,
+                translations,
+                playhead,
+                now: chrono::Local::now(),
+            };
+
+            return stack![
+                self.build_template_display(template, layout, size, song_info, &context, opacity)
+            ]
+            .extend(playhead.and_then(|p| self.build_progress(p, size, opacity)))
+            .into();
+        }
+
+        let language = self.language.resolve();
+        let text_size = |style: &ElementStyle| size.height * style.size / 100.0;
+
+        let dance_spacing = size.height / 35.0;
+        let song_spacing = size.height / 150.0;
+
+        let mut column_center = column![vertical()]
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .spacing(dance_spacing);
+
+        // consecutive title and artist elements form one block next to the cover
+        let central = layout.central_elements();
+        let mut i = 0;
+        while i < central.len() {
+            let style = &central[i];
+            if style.kind == LayoutElementKind::Dance {
+                let dance = self
+                    .fitted_text(&song_info.dance, style, text_size(style), opacity)
+                    .width(Length::Fill);
+
+                // the translation sits right below the dance, closer than the other elements
+                column_center = match translate(&song_info.dance) {
+                    Some(translation) => column_center.push(column![
+                        dance,
+                        self.fitted_text(
+                            translation.to_owned(),
+                            style,
+                            text_size(style) * 0.5,
+                            opacity
+                        )
+                        .width(Length::Fill),
+                    ]),
+                    None => column_center.push(dance),
+                };
+                i += 1;
+                continue;
+            }
+
+            let block: Vec<&ElementStyle> = central[i..]
+                .iter()
+                .take_while(|s| s.kind != LayoutElementKind::Dance)
+                .collect();
+            i += block.len();
+
+            let cover_height = block
+                .iter()
+                .map(|s| LineHeight::default().to_absolute(text_size(s).into()).0)
+                .sum::<f32>()
+                + song_spacing * (block.len() - 1) as f32;
+
+            let column_song = column(block.iter().map(|s| {
+                let content = match s.kind {
+                    LayoutElementKind::Artist => &song_info.artist,
+                    _ => &song_info.title,
+                };
+                self.fitted_text(content, s, text_size(s), opacity).into()
+            }))
+            .spacing(song_spacing)
+            .align_x(block[0].align);
+
+            let row_song = match song_info.album_art.as_ref() {
+                Some(image_handle) if self.enable_image => {
+                    row![
+                        ProjectedImage::new(
+                            image(image_handle).height(cover_height).opacity(opacity),
+                            ImageRun::new(image_handle, opacity)
+                        ),
+                        column_song
+                    ]
+                }
+                _ => row![column_song],
+            }
+            .spacing(song_spacing);
+
+            column_center = column_center.push(
+                container(row_song)
+                    .width(Length::Fill)
+                    .align_x(block[0].align),
+            );
+        }
+
+        let mut song_stack = stack![column_center.push(vertical())]
+            .width(Length::Fill)
+            .height(Length::Fill);
+
+        if self.enable_next_dance && self.next_dance_count > 1 && !upcoming.is_empty() {
+            let style = layout.element(LayoutElementKind::NextDance);
+            let next_dance_size = text_size(&style);
+
+            let dances = upcoming.iter().map(|dance| {
+                let label = match translate(dance) {
+                    Some(translation) => format!("{} / {}", dance, translation),
+                    None => dance.clone(),
+                };
+
+                self.fitted_text(label, &style, next_dance_size * 0.7, opacity)
+                    .into()
+            });
+
+            song_stack = song_stack.push(overlay(
+                column![
+                    self.fitted_text(
+                        language.translate("Next Dances"),
+                        &style,
+                        next_dance_size * 0.8,
+                        opacity
+                    ),
+                    column(dances).align_x(style.align),
+                ]
+                .align_x(style.align)
+                .spacing(dance_spacing / 3.0),
+                &style,
+            ));
+        } else if self.enable_next_dance
+            && let Some(next_song_info) = next_song_info
+        {
+            let style = layout.element(LayoutElementKind::NextDance);
+            let next_dance_size = text_size(&style);
+
+            let mut next_dance = column![
+                self.fitted_text(
+                    language.translate("Next Dance"),
+                    &style,
+                    next_dance_size * 0.8,
+                    opacity,
+                ),
+                self.fitted_text(&next_song_info.dance, &style, next_dance_size, opacity),
+            ]
+            .align_x(style.align)
+            .spacing(dance_spacing / 3.0);
+
+            if let Some(translation) = translate(&next_song_info.dance) {
+                next_dance = next_dance.push(self.fitted_text(
+                    translation.to_owned(),
+                    &style,
+                    next_dance_size * 0.6,
+                    opacity,
+                ));
+            }
+
+            song_stack = song_stack.push(overlay(next_dance, &style));
+        }
+
+        if self.enable_section
+            && let Some(section) = section
+        {
+            let style = layout.element(LayoutElementKind::Section);
+            song_stack = song_stack.push(overlay(
+                self.fitted_text(section, &style, text_size(&style), opacity),
+                &style,
+            ));
+        }
+
+        song_stack
+            .extend(playhead.and_then(|p| self.build_progress(p, size, opacity)))
+            .into()
+    }
+
+    /// Builds the clock and the countdown as set for the window. They are
+    /// shown whatever the song, and are not part of song transitions.
+    fn build_clock<'a>(
+        &self,
+        size: Size,
+        countdown: Option<&RunningCountdown>,
+    ) -> Element<'a, Message> {
+        let layout = self.layouts.active();
+        let now = chrono::Local::now();
+
+        let mut texts = Vec::new();
+        if self.enable_clock {
+            texts.push((LayoutElementKind::Clock, now.format("%H:%M").to_string()));
+        }
+        if self.enable_countdown
+            && let Some(countdown) = countdown
+        {
+            texts.push((LayoutElementKind::Countdown, countdown.text(now)));
+        }
+
+        stack(texts.into_iter().map(|(kind, content)| {
+            let style = layout.element(kind);
+            let text_size = size.height * style.size / 100.0;
+            overlay(self.fitted_text(content, &style, text_size, 1.0), &style)
+        }))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+    }
+
+    /// Builds the progress bar and the remaining time of the track as set for
+    /// the window, in the style of the progress element.
+    fn build_progress<'a>(
+        &self,
+        playhead: Playhead,
+        size: Size,
+        opacity: f32,
+    ) -> Option<Element<'a, Message>> {
+        if self.progress == ProgressDisplay::Off {
+            return None;
+        }
+
+        let style = self.layouts.active().element(LayoutElementKind::Progress);
+        let text_size = size.height * style.size / 100.0;
+
+        let mut progress = column![]
+            .width(Length::Fill)
+            .align_x(style.align)
+            .spacing(text_size / 4.0);
+
+        if self.progress.shows_remaining() {
+            let label = format!(
+                "{} {}",
+                self.language.resolve().translate("remaining"),
+                format_remaining(playhead.remaining())
+            );
+            progress = progress.push(self.fitted_text(label, &style, text_size, opacity));
+        }
+
+        if self.progress.shows_bar() {
+            let color = style.color.color();
+            let played = (playhead.progress() * 1000.0).round() as u16;
+
+            let parts = [
+                (played, color.scale_alpha(opacity)),
+                (1000 - played, color.scale_alpha(0.3 * opacity)),
+            ];
+            progress = progress.push(
+                row(parts
+                    .into_iter()
+                    .filter(|(portion, _)| *portion > 0)
+                    .map(|(portion, color)| bar_part(portion, color)))
+                .height(text_size / 3.0),
+            );
+        }
+
+        Some(overlay(progress, &style))
+    }
+
+    /// Builds a text in the style of a layout element. Long texts shrink to
+    /// the width available and overflow as set in the style. Marquees are cut
+    /// off with an ellipsis instead while the content is turned or mirrored,
+    /// as scrolling texts cannot be clipped there.
+    fn fitted_text<'a>(
+        &self,
+        content: impl Into<Cow<'a, str>>,
+        style: &ElementStyle,
+        size: f32,
+        opacity: f32,
+    ) -> FitText<'a, Renderer> {
+        FitText::new(content, size)
+            .min_size(size * style.min_scale / 100.0)
+            .marquee(style.overflow == TextOverflow::Marquee && !self.readability.is_transformed())
+            .font(style.font())
+            .color(style.color.color().scale_alpha(opacity))
+            .align_x(style.align)
+            .outline(self.readability.outline_color(opacity))
+            .shadow(self.readability.shadow_color(opacity))
+    }
+
+    fn build_template_display<'a>(
+        &self,
+        template: &SongWindowTemplate,
+        layout: &SongWindowLayout,
+        size: Size,
+        song_info: &'a SongInfo,
+        context: &TemplateContext,
+        opacity: f32,
+    ) -> Element<'a, Message> {
+        let dance_spacing = size.height / 35.0;
+        let song_spacing = size.height / 150.0;
+
+        let mut column_center = column![vertical()]
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .spacing(dance_spacing);
+        let mut overlays: Vec<Element<_>> = Vec::new();
+
+        for element in &template.elements {
+            let enabled = match element.style {
+                LayoutElementKind::NextDance => self.enable_next_dance,
+                LayoutElementKind::Section => self.enable_section,
+                _ => true,
+            };
+
+            if !enabled {
+                continue;
+            }
+            let Some(content) = context.render(&element.text) else {
+                continue;
+            };
+
+            let style = element.resolve_style(layout);
+            let text_size = size.height * style.size / 100.0;
+            let text = self.fitted_text(content, &style, text_size, opacity);
+
+            let content: Element<_> = match song_info.album_art.as_ref() {
+                Some(image_handle) if element.cover && self.enable_image => row![
+                    ProjectedImage::new(
+                        image(image_handle)
+                            .height(LineHeight::default().to_absolute(text_size.into()).0)
+                            .opacity(opacity),
+                        ImageRun::new(image_handle, opacity)
+                    ),
+                    text
+                ]
+                .spacing(song_spacing)
+                .into(),
+                _ => text.into(),
+            };
+
+            if element.is_overlay() {
+                overlays.push(overlay(content, &style));
+            } else {
+                column_center =
+                    column_center.push(container(content).width(Length::Fill).align_x(style.align));
+            }
+        }
+
+        stack![column_center.push(vertical())]
+            .extend(overlays)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+fn with_background<'a>(
+    content: Element<'a, Message>,
+    layout: &SongWindowLayout,
+) -> Element<'a, Message> {
+    let background = layout.background.color();
+
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(move |_| container::Style::default().background(background))
+        .into()
+}
+
+fn overlay<'a>(
+    content: impl Into<Element<'a, Message>>,
+    style: &ElementStyle,
+) -> Element<'a, Message> {
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(style.align)
+        .align_y(style.vertical)
+        .into()
+}
+
+/// Builds a part of the progress bar, filling the given portion of its width.
+fn bar_part<'a>(portion: u16, color: Color) -> Element<'a, Message> {
+    Projected::new(
+        container(Space::new())
+            .width(Length::FillPortion(portion))
+            .height(Length::Fill)
+            .style(move |_| container::Style::default().background(color)),
+        FillRun { color },
+    )
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Window;
+    use crate::ui::song_window::SongWindow;
+    use iced::window;
+
+    #[test]
+    fn broken_templates_stay_selected_and_keep_their_last_version() {
+        let path = std::env::temp_dir().join("danceinterpreter_template_reload_test.json");
+        let mut song_window = SongWindow::new(window::Id::unique());
+
+        assert!(song_window.set_template(Some(path.clone())).is_err());
+        assert_eq!(song_window.template_path(), Some(&path));
+        assert!(song_window.template.is_none());
+        assert!(song_window.template_error.is_some());
+
+        std::fs::write(&path, r#"{ "name": "Bar", "elements": [] }"#).unwrap();
+        assert!(song_window.set_template(Some(path.clone())).is_ok());
+        assert!(song_window.template_error.is_none());
+
+        std::fs::write(&path, "{ \"name\": ").unwrap();
+        assert!(song_window.set_template(Some(path.clone())).is_err());
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(song_window.template_path(), Some(&path));
+        assert!(song_window.template.is_some());
+        assert!(song_window.template_error.is_some());
+    }
+}
@@ -0,0 +1,77 @@
+use iced::window::{self, Level, Position};
+use iced::{Point, Size};
+use serde::{Deserialize, Serialize};
+
+/// Where a display window was placed, in logical pixels.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowPlacement {
+    /// Position in windowed mode.
+    pub position: Option<[f32; 2]>,
+    /// Size in windowed mode.
+    pub size: Option<[f32; 2]>,
+    pub fullscreen: bool,
+    /// Size of the monitor the window was on, used to detect whether that
+    /// monitor is still connected.
+    pub monitor_size: Option<[f32; 2]>,
+}
+
+impl WindowPlacement {
+    /// Applies the placement and the window options to the settings the window is opened with.
+    pub fn window_settings(
+        &self,
+        borderless: bool,
+        always_on_top: bool,
+        settings: window::Settings,
+    ) -> window::Settings {
+        window::Settings {
+            position: self.position.map_or(Position::Default, |[x, y]| {
+                Position::Specific(Point::new(x, y))
+            }),
+            size: self
+                .size
+                .map_or(settings.size, |[width, height]| Size::new(width, height)),
+            fullscreen: self.fullscreen,
+            decorations: !borderless,
+            level: if always_on_top {
+                Level::AlwaysOnTop
+            } else {
+                Level::Normal
+            },
+            ..settings
+        }
+    }
+
+    /// Whether the window ended up on the monitor it was saved on. Monitors
+    /// are told apart by their size, as that is all the windowing layer reports.
+    pub fn is_on_saved_monitor(&self, monitor_size: Option<Size>) -> bool {
+        let Some([width, height]) = self.monitor_size else {
+            return true;
+        };
+
+        monitor_size.is_some_and(|size| {
+            (size.width - width).abs() < 1.0 && (size.height - height).abs() < 1.0
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::song_window::placement::WindowPlacement;
+    use iced::Size;
+
+    #[test]
+    fn missing_monitor_is_detected() {
+        let placement = WindowPlacement {
+            position: Some([1920.0, 0.0]),
+            fullscreen: true,
+            monitor_size: Some([1280.0, 720.0]),
+            ..Default::default()
+        };
+
+        assert!(placement.is_on_saved_monitor(Some(Size::new(1280.0, 720.0))));
+        assert!(!placement.is_on_saved_monitor(Some(Size::new(1920.0, 1080.0))));
+        assert!(!placement.is_on_saved_monitor(None));
+        assert!(WindowPlacement::default().is_on_saved_monitor(None));
+    }
+}
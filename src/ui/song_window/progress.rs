@@ -0,0 +1,66 @@
+use crate::i18n::tr;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
+
+/// How a song window shows the progress of the track playing in Traktor.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ProgressDisplay {
+    #[default]
+    Off,
+    Bar,
+    Remaining,
+    BarAndRemaining,
+}
+
+impl ProgressDisplay {
+    pub const ALL: [ProgressDisplay; 4] = [
+        ProgressDisplay::Off,
+        ProgressDisplay::Bar,
+        ProgressDisplay::Remaining,
+        ProgressDisplay::BarAndRemaining,
+    ];
+
+    pub fn shows_bar(self) -> bool {
+        matches!(
+            self,
+            ProgressDisplay::Bar | ProgressDisplay::BarAndRemaining
+        )
+    }
+
+    pub fn shows_remaining(self) -> bool {
+        matches!(
+            self,
+            ProgressDisplay::Remaining | ProgressDisplay::BarAndRemaining
+        )
+    }
+}
+
+impl Display for ProgressDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            ProgressDisplay::Off => "Off",
+            ProgressDisplay::Bar => "Bar",
+            ProgressDisplay::Remaining => "Remaining Time",
+            ProgressDisplay::BarAndRemaining => "Bar and Time",
+        };
+        f.write_str(tr(label))
+    }
+}
+
+/// Formats the time left as `m:ss`, or `h:mm:ss` from an hour on, rounded
+/// up so it reaches `0:00` at the end of the track.
+pub fn format_remaining(remaining: Duration) -> String {
+    let seconds = remaining.as_secs_f64().ceil() as u64;
+
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
@@ -0,0 +1,110 @@
+use iced::{Color, Padding, Size};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Turn of the song window content, for projectors mounted sideways or upside down.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Rotation {
+    #[default]
+    None,
+    Clockwise,
+    UpsideDown,
+    CounterClockwise,
+}
+
+impl Rotation {
+    pub const ALL: [Rotation; 4] = [
+        Rotation::None,
+        Rotation::Clockwise,
+        Rotation::UpsideDown,
+        Rotation::CounterClockwise,
+    ];
+
+    /// Number of clockwise quarter turns.
+    pub fn quarter_turns(self) -> u8 {
+        match self {
+            Rotation::None => 0,
+            Rotation::Clockwise => 1,
+            Rotation::UpsideDown => 2,
+            Rotation::CounterClockwise => 3,
+        }
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}°", u16::from(self.quarter_turns()) * 90)
+    }
+}
+
+/// Options that keep the song window readable on dim, overscanned, rotated
+/// or rear projections.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Readability {
+    pub outline: bool,
+    pub shadow: bool,
+    /// Margin kept free on every side, in percent of the window size.
+    pub safe_area: u32,
+    pub rotation: Rotation,
+    /// Mirrors the whole window horizontally, for rear projection.
+    pub mirrored: bool,
+}
+
+impl Readability {
+    pub const SAFE_AREAS: [u32; 6] = [0, 2, 4, 6, 8, 10];
+
+    /// Returns whether the content is turned or mirrored, and so drawn by a
+    /// [`Projection`](crate::ui::widget::projection::Projection) as geometry.
+    pub fn is_transformed(&self) -> bool {
+        self.rotation != Rotation::None || self.mirrored
+    }
+
+    pub fn outline_color(&self, opacity: f32) -> Option<Color> {
+        self.outline
+            .then(|| Color::from_rgba(0.0, 0.0, 0.0, 0.9 * opacity))
+    }
+
+    pub fn shadow_color(&self, opacity: f32) -> Option<Color> {
+        self.shadow
+            .then(|| Color::from_rgba(0.0, 0.0, 0.0, 0.6 * opacity))
+    }
+
+    /// Returns the size the content is laid out in on a window of the given
+    /// size, turned as set and without the safe area, and the padding around it.
+    pub fn content_area(&self, size: Size) -> (Size, Padding) {
+        let size = if self.rotation.quarter_turns() % 2 == 1 {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        };
+
+        let margin = self.safe_area.min(40) as f32 / 100.0;
+        let padding = Padding::ZERO
+            .vertical(size.height * margin)
+            .horizontal(size.width * margin);
+
+        let inner = Size::new(size.width - padding.x(), size.height - padding.y());
+        (inner, padding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::song_window::readability::{Readability, Rotation};
+    use iced::Size;
+
+    #[test]
+    fn turned_content_is_laid_out_inside_the_safe_area() {
+        let readability = Readability {
+            safe_area: 10,
+            rotation: Rotation::Clockwise,
+            ..Readability::default()
+        };
+
+        let (size, padding) = readability.content_area(Size::new(1920.0, 1080.0));
+        assert_eq!(size, Size::new(864.0, 1536.0));
+        assert_eq!(padding.left, 108.0);
+        assert_eq!(padding.top, 192.0);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::dataloading::dance_translations::DanceTranslations;
+use crate::dataloading::songinfo::SongInfo;
+use crate::traktor_api::Playhead;
+use crate::ui::song_window::layout::{
+    ElementStyle, HorizontalAlign, LayoutElementKind, SongWindowLayout, VerticalAlign,
+};
+use crate::ui::song_window::progress::format_remaining;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+use std::path::{Path, PathBuf};
+
+/// Song window described as a list of text elements with placeholders, e.g.
+///
+/// ```json
+/// {
+///   "name": "Bar",
+///   "elements": [
+///     { "text": "{dance}", "style": "Dance" },
+///     { "text": "{title} – {artist}", "style": "Artist", "cover": true },
+///     { "text": "{block}", "style": "Section" },
+///     { "text": "{time}", "style": "Section", "align": "Right" },
+///     { "text": "-{remaining}", "style": "Progress" }
+///   ]
+/// }
+/// ```
+///
+/// Fonts and colours are taken from the element of the active layout named
+/// by `style`, sizes and positions can be overridden per element.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SongWindowTemplate {
+    pub name: String,
+    pub elements: Vec<TemplateElement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateElement {
+    pub text: String,
+    #[serde(default = "default_style")]
+    pub style: LayoutElementKind,
+    /// Places the element in a corner or at an edge instead of the centered
+    /// column. Defaults to whether the layout element of `style` is an overlay.
+    #[serde(default)]
+    pub overlay: Option<bool>,
+    /// Shows the album art next to the text.
+    #[serde(default)]
+    pub cover: bool,
+    #[serde(default)]
+    pub size: Option<f32>,
+    #[serde(default)]
+    pub align: Option<HorizontalAlign>,
+    #[serde(default)]
+    pub vertical: Option<VerticalAlign>,
+}
+
+fn default_style() -> LayoutElementKind {
+    LayoutElementKind::Title
+}
+
+impl TemplateElement {
+    pub fn is_overlay(&self) -> bool {
+        self.overlay.unwrap_or(!self.style.is_central())
+    }
+
+    /// Returns the style of the layout element with the overrides of this element applied.
+    pub fn resolve_style(&self, layout: &SongWindowLayout) -> ElementStyle {
+        let mut style = layout.element(self.style);
+        style.size = self.size.unwrap_or(style.size);
+        style.align = self.align.unwrap_or(style.align);
+        style.vertical = self.vertical.unwrap_or(style.vertical);
+        style
+    }
+}
+
+/// Values the placeholders of a template are filled with.
+pub struct TemplateContext<'a> {
+    pub song: &'a SongInfo,
+    pub next: Option<&'a SongInfo>,
+    /// Dances of the next songs, consecutive identical dances collapsed.
+    pub upcoming: &'a [String],
+    pub section: Option<&'a str>,
+    /// Set while the window shows bilingual dance names.
+    pub translations: Option<&'a DanceTranslations>,
+    /// Set while Traktor plays the song on air.
+    pub playhead: Option<Playhead>,
+    pub now: DateTime<Local>,
+}
+
+impl TemplateContext<'_> {
+    fn value(&self, placeholder: &str) -> Option<String> {
+        let next = |f: fn(&SongInfo) -> &String| self.next.map(f).cloned().unwrap_or_default();
+        let translate = |dance: Option<&String>| {
+            self.translations
+                .zip(dance)
+                .and_then(|(t, dance)| t.translate(dance))
+                .unwrap_or_default()
+                .to_owned()
+        };
+
+        let value = match placeholder {
+            "dance" => self.song.dance.clone(),
+            "title" => self.song.title.clone(),
+            "artist" => self.song.artist.clone(),
+            "album" => self.song.album.clone(),
+            "key" => self.song.key.clone(),
+            "bpm" => self
+                .song
+                .bpm
+                .map(|bpm| format!("{:.0}", bpm))
+                .unwrap_or_default(),
+            "duration" => self
+                .song
+                .duration
+                .map(|d| format!("{}:{:02}", d.as_secs() / 60, d.as_secs() % 60))
+                .unwrap_or_default(),
+            "next_dance" => next(|s| &s.dance),
+            "next_title" => next(|s| &s.title),
+            "next_artist" => next(|s| &s.artist),
+            "dance_translation" => translate(Some(&self.song.dance)),
+            "next_dance_translation" => translate(self.next.map(|s| &s.dance)),
+            "next_dances" => self.upcoming.join(" · "),
+            "block" => self.section.unwrap_or_default().to_owned(),
+            "remaining" => self
+                .playhead
+                .map(|p| format_remaining(p.remaining()))
+                .unwrap_or_default(),
+            "time" => self.now.format("%H:%M").to_string(),
+            "date" => self.now.format("%d.%m.%Y").to_string(),
+            _ => return None,
+        };
+
+        Some(value)
+    }
+
+    /// Replaces the `{placeholder}`s in the text. Unknown placeholders are kept as they are.
+    /// Returns `None` if the text has placeholders and all of them are empty,
+    /// e.g. for a next dance label while there is no next song.
+    pub fn render(&self, text: &str) -> Option<String> {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        let mut placeholders = 0;
+        let mut empty = 0;
+
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+
+            match self.value(&rest[1..end]) {
+                Some(value) => {
+                    placeholders += 1;
+                    if value.trim().is_empty() {
+                        empty += 1;
+                    }
+                    result.push_str(&value);
+                }
+                None => result.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+
+        result.push_str(rest);
+        (placeholders == 0 || empty < placeholders).then_some(result)
+    }
+}
+
+impl SongWindowTemplate {
+    /// Directory that is searched for template files.
+    pub fn directory() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("danceinterpreter").join("templates"))
+    }
+
+    /// Returns the template files in the template directory, sorted by name.
+    pub fn list() -> Vec<PathBuf> {
+        let Some(entries) = Self::directory().and_then(|dir| std::fs::read_dir(dir).ok()) else {
+            return Vec::new();
+        };
+
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("json"))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(std::io::Error::other)
+    }
+
+    /// Whether the template shows the time or the remaining time of the
+    /// track and has to be redrawn periodically.
+    pub fn uses_clock(&self) -> bool {
+        self.elements.iter().any(|e| {
+            ["{time}", "{date}", "{remaining}"]
+                .iter()
+                .any(|placeholder| e.text.contains(placeholder))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::songinfo::SongInfo;
+    use crate::ui::song_window::template::{SongWindowTemplate, TemplateContext};
+    use chrono::Local;
+
+    #[test]
+    fn placeholders_are_replaced() {
+        let song = SongInfo::new(
+            1,
+            "Moon River".to_owned(),
+            "Andy Williams".to_owned(),
+            "Langsamer Walzer".to_owned(),
+            None,
+        );
+        let next = SongInfo::with_dance("Tango".to_owned());
+        let context = TemplateContext {
+            song: &song,
+            next: Some(&next),
+            upcoming: &[],
+            section: None,
+            translations: None,
+            playhead: None,
+            now: Local::now(),
+        };
+
+        assert_eq!(
+            context.render("{title} – {artist}").as_deref(),
+            Some("Moon River – Andy Williams")
+        );
+        assert_eq!(
+            context.render("Next: {next_dance}{block}").as_deref(),
+            Some("Next: Tango")
+        );
+        assert_eq!(context.render("Block: {block}"), None);
+        assert_eq!(
+            context.render("{unknown} {dance").as_deref(),
+            Some("{unknown} {dance")
+        );
+    }
+
+    #[test]
+    fn template_elements_default_to_title_style() {
+        let template: SongWindowTemplate = serde_json::from_str(
+            r#"{ "name": "Bar", "elements": [ { "text": "{title}" }, { "text": "{block}", "style": "Section" } ] }"#,
+        )
+        .unwrap();
+
+        assert!(!template.elements[0].is_overlay());
+        assert!(template.elements[1].is_overlay());
+        assert!(!template.uses_clock());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::dataloading::dataprovider::song_data_provider::SongDataSource;
+use crate::dataloading::songinfo::SongInfo;
+use crate::i18n::tr;
+use crate::ui::song_window::SongDisplay;
+use iced::{Animation, animation};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransitionKind {
+    None,
+    Crossfade,
+    Slide,
+    FadeThroughBlack,
+}
+
+impl TransitionKind {
+    pub const ALL: [TransitionKind; 4] = [
+        TransitionKind::None,
+        TransitionKind::Crossfade,
+        TransitionKind::Slide,
+        TransitionKind::FadeThroughBlack,
+    ];
+}
+
+impl Display for TransitionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            TransitionKind::None => tr("None"),
+            TransitionKind::Crossfade => tr("Crossfade"),
+            TransitionKind::Slide => tr("Slide"),
+            TransitionKind::FadeThroughBlack => tr("Fade Through Black"),
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransitionSettings {
+    pub kind: TransitionKind,
+    pub duration_ms: u64,
+}
+
+impl TransitionSettings {
+    pub const DURATIONS_MS: [u64; 6] = [250, 500, 750, 1000, 1500, 2000];
+}
+
+impl Default for TransitionSettings {
+    fn default() -> Self {
+        Self {
+            kind: TransitionKind::None,
+            duration_ms: 500,
+        }
+    }
+}
+
+/// What the song window showed, kept so the old song can still be drawn
+/// while the transition to the new one runs.
+#[derive(Debug, Clone)]
+pub struct DisplayedSong {
+    pub source: SongDataSource,
+    pub song: Option<SongInfo>,
+    pub next: Option<SongInfo>,
+    pub upcoming: Vec<String>,
+    pub section: Option<String>,
+}
+
+impl DisplayedSong {
+    pub fn display(&self) -> Option<SongDisplay<'_>> {
+        self.song.as_ref().map(|song| SongDisplay {
+            song,
+            next: self.next.as_ref(),
+            upcoming: &self.upcoming,
+            section: self.section.as_deref(),
+            playhead: None,
+        })
+    }
+}
+
+pub struct Transition {
+    pub settings: TransitionSettings,
+    progress: Animation<bool>,
+    shown: Option<DisplayedSong>,
+    previous: Option<DisplayedSong>,
+}
+
+impl Transition {
+    pub fn new(settings: TransitionSettings) -> Self {
+        Self {
+            settings,
+            progress: Animation::new(true),
+            shown: None,
+            previous: None,
+        }
+    }
+
+    /// Starts a transition if the shown song changed since the last call.
+    /// The song is compared besides the kind of source, as Traktor keeps its
+    /// source while the track changes and inserting songs into the playlist
+    /// shifts the index of the current one.
+    pub fn observe(
+        &mut self,
+        source: &SongDataSource,
+        song: Option<&SongInfo>,
+        next: Option<&SongInfo>,
+        upcoming: &[&str],
+        section: Option<&str>,
+        now: Instant,
+    ) {
+        let changed = match &self.shown {
+            Some(shown) => {
+                std::mem::discriminant(&shown.source) != std::mem::discriminant(source)
+                    || shown.song.as_ref() != song
+            }
+            None => true,
+        };
+        if !changed {
+            let Some(shown) = &mut self.shown else {
+                return;
+            };
+            shown.source = source.clone();
+
+            // the queue can change while the song stays the same
+            if shown
+                .upcoming
+                .iter()
+                .map(String::as_str)
+                .ne(upcoming.iter().copied())
+            {
+                shown.upcoming = upcoming.iter().map(|d| d.to_string()).collect();
+            }
+            return;
+        }
+
+        let displayed = DisplayedSong {
+            source: source.clone(),
+            song: song.cloned(),
+            next: next.cloned(),
+            upcoming: upcoming.iter().map(|d| d.to_string()).collect(),
+            section: section.map(str::to_owned),
+        };
+
+        // the first song appears without a transition
+        self.previous = self.shown.replace(displayed);
+        if self.previous.is_none() || self.settings.kind == TransitionKind::None {
+            return;
+        }
+
+        self.progress = Animation::new(false)
+            .duration(Duration::from_millis(self.settings.duration_ms))
+            .easing(animation::Easing::EaseInOut)
+            .go(true, now);
+    }
+
+    /// Dances of the next songs, as of the last call to [`Transition::observe`].
+    pub fn upcoming(&self) -> &[String] {
+        self.shown.as_ref().map_or(&[], |shown| &shown.upcoming)
+    }
+
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.settings.kind != TransitionKind::None && self.progress.is_animating(now)
+    }
+
+    /// Returns the song shown before and how far the transition away from it
+    /// has progressed, from 0 to 1, while a transition runs.
+    pub fn progress(&self, now: Instant) -> Option<(&DisplayedSong, f32)> {
+        if !self.is_animating(now) {
+            return None;
+        }
+
+        self.previous
+            .as_ref()
+            .map(|previous| (previous, self.progress.interpolate(0.0, 1.0, now)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dataloading::dataprovider::song_data_provider::SongDataSource;
+    use crate::ui::song_window::transition::{Transition, TransitionKind, TransitionSettings};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn source_change_starts_transition() {
+        let mut transition = Transition::new(TransitionSettings {
+            kind: TransitionKind::Crossfade,
+            duration_ms: 500,
+        });
+        let now = Instant::now();
+
+        transition.observe(&SongDataSource::Playlist(0), None, None, &[], None, now);
+        assert!(transition.progress(now).is_none());
+
+        transition.observe(&SongDataSource::Playlist(0), None, None, &[], None, now);
+        assert!(transition.progress(now).is_none());
+
+        // songs inserted before the current one only shift its index
+        transition.observe(&SongDataSource::Playlist(2), None, None, &[], None, now);
+        assert!(transition.progress(now).is_none());
+
+        transition.observe(&SongDataSource::Blank, None, None, &[], None, now);
+        let (previous, _) = transition.progress(now).unwrap();
+        assert_eq!(previous.source, SongDataSource::Playlist(2));
+        assert!(transition.progress(now + Duration::from_secs(1)).is_none());
+    }
+
+    #[test]
+    fn queue_changes_refresh_upcoming_dances() {
+        let mut transition = Transition::new(TransitionSettings::default());
+        let now = Instant::now();
+
+        transition.observe(
+            &SongDataSource::Playlist(0),
+            None,
+            None,
+            &["Tango"],
+            None,
+            now,
+        );
+        transition.observe(
+            &SongDataSource::Playlist(0),
+            None,
+            None,
+            &["Jive"],
+            None,
+            now,
+        );
+
+        assert_eq!(transition.upcoming(), ["Jive"]);
+        assert!(transition.progress(now).is_none());
+    }
+}
@@ -1,12 +1,12 @@
 use iced::advanced::graphics::core::{Element, touch};
 use iced::advanced::text::Wrapping;
 use iced::advanced::widget::{Operation, Tree, tree};
-use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, overlay, renderer, text};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer, text};
 use iced::keyboard::Key;
 use iced::keyboard::key::Named;
 use iced::widget::text::{LineHeight, Shaping};
 use iced::widget::{Text, TextInput, text_input};
-use iced::{Color, Event, Length, Pixels, Rectangle, Size, Vector, alignment, keyboard};
+use iced::{Color, Event, Length, Pixels, Rectangle, Size, alignment, keyboard};
 
 #[allow(missing_debug_implementations)]
 pub struct DynamicTextInput<'a, Message, Theme = iced::Theme, Renderer = iced::Renderer>
@@ -16,13 +16,23 @@ where
     Theme: 'a + text_input::Catalog + iced::widget::text::Catalog,
 {
     content_label: Text<'a, Theme, Renderer>,
-    content_input: TextInput<'a, Message, Theme, Renderer>,
+    /// Rebuilt from the value or the draft on every layout, see `build_input`.
+    content_input: TextInput<'a, String, Theme, Renderer>,
+
+    placeholder: String,
+    value: String,
+    size: Option<Pixels>,
+    line_height: Option<LineHeight>,
+    align_x: Option<alignment::Horizontal>,
 
     width: Length,
     interaction: Option<mouse::Interaction>,
+    editing: bool,
 
+    on_change: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_enter: Option<Message>,
     on_submit: Option<Message>,
+    on_cancel: Option<Message>,
 }
 
 impl<'a, Message, Theme, Renderer> DynamicTextInput<'a, Message, Theme, Renderer>
@@ -36,35 +46,41 @@ where
         self
     }
 
-    fn get_widget(&self, is_edit_mode: bool) -> &dyn Widget<Message, Theme, Renderer> {
-        if is_edit_mode {
-            &self.content_input
-        } else {
-            &self.content_label
-        }
-    }
+    fn build_input(&self, value: &str) -> TextInput<'a, String, Theme, Renderer> {
+        let mut input = TextInput::new(&self.placeholder, value)
+            .padding(0)
+            .width(self.width)
+            .on_input_maybe(self.on_change.as_ref().map(|_| |value: String| value));
 
-    fn get_widget_mut(&mut self, is_edit_mode: bool) -> &mut dyn Widget<Message, Theme, Renderer> {
-        if is_edit_mode {
-            &mut self.content_input
-        } else {
-            &mut self.content_label
+        if let Some(size) = self.size {
+            input = input.size(size);
+        }
+        if let Some(line_height) = self.line_height {
+            input = input.line_height(line_height);
         }
+        if let Some(align_x) = self.align_x {
+            input = input.align_x(align_x);
+        }
+
+        input
     }
 }
 
+/// Index of the label in the children built by `children`.
+const LABEL_CHILD: usize = 0;
+/// Index of the text input in the children built by `children`.
+const INPUT_CHILD: usize = 1;
+
 #[derive(Default)]
 struct State {
     is_pressed: bool,
     is_edit_mode: bool,
+    edit_requested: bool,
 
-    previous_click: Option<mouse::Click>,
-}
+    /// The text typed since entering edit mode, committed with `on_change` when editing ends.
+    draft: Option<String>,
 
-impl State {
-    fn get_child_index(&self) -> usize {
-        if self.is_edit_mode { 1 } else { 0 }
-    }
+    previous_click: Option<mouse::Click>,
 }
 
 fn get_placeholder_color<Theme: text_input::Catalog>(theme: &Theme) -> Color {
@@ -85,8 +101,6 @@ where
         <Theme as iced::widget::text::Catalog>::Class<'a>:
             From<iced::widget::text::StyleFn<'a, Theme>>,
     {
-        let input = TextInput::new(placeholder, value).padding(0);
-
         let label = if !value.is_empty() {
             Text::new(value.to_owned())
                 .wrapping(Wrapping::None)
@@ -101,21 +115,29 @@ where
         };
 
         DynamicTextInput {
-            content_input: input,
+            content_input: TextInput::new(placeholder, value),
             content_label: label,
 
+            placeholder: placeholder.to_owned(),
+            value: value.to_owned(),
+            size: None,
+            line_height: None,
+            align_x: None,
+
             width: Length::Fill,
             interaction: None,
+            editing: false,
 
+            on_change: None,
             on_enter: None,
             on_submit: None,
+            on_cancel: None,
         }
     }
 
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         let width = width.into();
         self.width = width;
-        self.content_input = self.content_input.width(width);
         self.content_label = self.content_label.width(width);
 
         self
@@ -123,7 +145,7 @@ where
 
     pub fn size(mut self, size: impl Into<Pixels>) -> Self {
         let size = size.into();
-        self.content_input = self.content_input.size(size);
+        self.size = Some(size);
         self.content_label = self.content_label.size(size);
 
         self
@@ -131,7 +153,7 @@ where
 
     pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
         let line_height = line_height.into();
-        self.content_input = self.content_input.line_height(line_height);
+        self.line_height = Some(line_height);
         self.content_label = self.content_label.line_height(line_height);
 
         self
@@ -139,14 +161,16 @@ where
 
     pub fn align_x(mut self, alignment: impl Into<alignment::Horizontal>) -> Self {
         let alignment = alignment.into();
-        self.content_input = self.content_input.align_x(alignment);
+        self.align_x = Some(alignment);
         self.content_label = self.content_label.align_x(alignment);
 
         self
     }
 
+    /// Sets the message produced with the edited text when editing ends, unless it is cancelled.
+    /// The text typed in between is kept in the widget state.
     pub fn on_change(mut self, on_change: impl Fn(String) -> Message + 'a) -> Self {
-        self.content_input = self.content_input.on_input(on_change);
+        self.on_change = Some(Box::new(on_change));
         self
     }
 
@@ -159,6 +183,20 @@ where
         self.on_submit = Some(on_submit);
         self
     }
+
+    /// Sets the message produced when editing is aborted with escape.
+    /// The `on_submit` message is not produced in that case.
+    pub fn on_cancel(mut self, on_cancel: Message) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+
+    /// Enters edit mode when this changes to `true` and leaves it when it changes to `false`.
+    /// The widget can still enter and leave edit mode on its own in between.
+    pub fn editing(mut self, editing: bool) -> Self {
+        self.editing = editing;
+        self
+    }
 }
 
 impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -178,13 +216,27 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        apply_requested_edit_mode::<Renderer>(tree, self.editing);
+
         let state: &State = tree.state.downcast_ref();
 
-        self.get_widget_mut(state.is_edit_mode).layout(
-            &mut tree.children[state.get_child_index()],
-            renderer,
-            limits,
-        )
+        if state.is_edit_mode {
+            self.content_input = self.build_input(state.draft.as_deref().unwrap_or(&self.value));
+
+            Widget::<String, Theme, Renderer>::layout(
+                &mut self.content_input,
+                &mut tree.children[INPUT_CHILD],
+                renderer,
+                limits,
+            )
+        } else {
+            Widget::<Message, Theme, Renderer>::layout(
+                &mut self.content_label,
+                &mut tree.children[LABEL_CHILD],
+                renderer,
+                limits,
+            )
+        }
     }
 
     fn draw(
@@ -199,15 +251,29 @@ where
     ) {
         let state: &State = tree.state.downcast_ref();
 
-        self.get_widget(state.is_edit_mode).draw(
-            &tree.children[state.get_child_index()],
-            renderer,
-            theme,
-            renderer_style,
-            layout,
-            cursor,
-            viewport,
-        );
+        if state.is_edit_mode {
+            Widget::<String, Theme, Renderer>::draw(
+                &self.content_input,
+                &tree.children[INPUT_CHILD],
+                renderer,
+                theme,
+                renderer_style,
+                layout,
+                cursor,
+                viewport,
+            );
+        } else {
+            Widget::<Message, Theme, Renderer>::draw(
+                &self.content_label,
+                &tree.children[LABEL_CHILD],
+                renderer,
+                theme,
+                renderer_style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
     }
 
     fn tag(&self) -> tree::Tag {
@@ -219,17 +285,18 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
+        // In the order of `LABEL_CHILD` and `INPUT_CHILD`
         vec![
             Tree::new(&self.content_label as &dyn Widget<Message, Theme, Renderer>),
-            Tree::new(&self.content_input as &dyn Widget<Message, Theme, Renderer>),
+            Tree::new(&self.content_input as &dyn Widget<String, Theme, Renderer>),
         ]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[
-            &self.content_label as &dyn Widget<Message, Theme, Renderer>,
-            &self.content_input as &dyn Widget<Message, Theme, Renderer>,
-        ]);
+        tree.children[LABEL_CHILD]
+            .diff(&self.content_label as &dyn Widget<Message, Theme, Renderer>);
+        tree.children[INPUT_CHILD]
+            .diff(&self.content_input as &dyn Widget<String, Theme, Renderer>);
     }
 
     fn operate(
@@ -240,12 +307,24 @@ where
         operation: &mut dyn Operation,
     ) {
         let state: &State = tree.state.downcast_ref();
-        self.get_widget_mut(state.is_edit_mode).operate(
-            &mut tree.children[state.get_child_index()],
-            layout,
-            renderer,
-            operation,
-        );
+
+        if state.is_edit_mode {
+            Widget::<String, Theme, Renderer>::operate(
+                &mut self.content_input,
+                &mut tree.children[INPUT_CHILD],
+                layout,
+                renderer,
+                operation,
+            );
+        } else {
+            Widget::<Message, Theme, Renderer>::operate(
+                &mut self.content_label,
+                &mut tree.children[LABEL_CHILD],
+                layout,
+                renderer,
+                operation,
+            );
+        }
     }
 
     fn update(
@@ -260,34 +339,89 @@ where
         viewport: &Rectangle,
     ) {
         let state: &mut State = tree.state.downcast_mut();
-        let content = self.get_widget_mut(state.is_edit_mode);
 
-        content.update(
-            &mut tree.children[state.get_child_index()],
-            event,
-            layout,
-            cursor,
-            renderer,
-            clipboard,
-            shell,
-            viewport,
-        );
+        if state.is_edit_mode
+            && let Some(on_cancel) = self.on_cancel.clone()
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) = event
+        {
+            let input_state: &mut text_input::State<Renderer::Paragraph> =
+                tree.children[INPUT_CHILD].state.downcast_mut();
+            input_state.unfocus();
+            state.is_edit_mode = false;
+            state.draft = None;
+
+            shell.publish(on_cancel);
+            shell.capture_event();
+            shell.invalidate_layout();
+            shell.request_redraw();
+            return;
+        }
 
         if state.is_edit_mode {
+            let mut local_messages = Vec::new();
+            let mut local_shell = Shell::new(&mut local_messages);
+
+            Widget::<String, Theme, Renderer>::update(
+                &mut self.content_input,
+                &mut tree.children[INPUT_CHILD],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                &mut local_shell,
+                viewport,
+            );
+
+            if local_shell.is_event_captured() {
+                shell.capture_event();
+            }
+            if local_shell.is_layout_invalid() {
+                shell.invalidate_layout();
+            }
+            shell.request_redraw_at(local_shell.redraw_request());
+            shell.request_input_method(local_shell.input_method());
+
+            // keep the typed text until editing ends instead of publishing every keystroke
+            if let Some(draft) = local_messages.pop() {
+                self.content_input = self.build_input(&draft);
+                state.draft = Some(draft);
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+            }
+
             let input_state: &mut text_input::State<Renderer::Paragraph> =
-                tree.children[state.get_child_index()].state.downcast_mut();
+                tree.children[INPUT_CHILD].state.downcast_mut();
 
             if input_state.is_focused()
                 && let Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) = event.clone()
-                && key == Key::Named(Named::Enter)
             {
-                input_state.unfocus();
-                shell.capture_event();
+                match key {
+                    Key::Named(Named::Enter) => {
+                        input_state.unfocus();
+                        shell.capture_event();
+                    }
+                    // Tab moves the edit to another cell from outside, which
+                    // may drop this widget, so the draft is committed right away.
+                    // The event is left to the application to move the cursor.
+                    Key::Named(Named::Tab) => input_state.unfocus(),
+                    _ => {}
+                }
             }
 
             if !input_state.is_focused() {
                 state.is_edit_mode = false;
 
+                if let Some(draft) = state.draft.take()
+                    && let Some(on_change) = &self.on_change
+                {
+                    shell.publish(on_change(draft));
+                }
+
                 if let Some(message) = self.on_submit.clone() {
                     shell.publish(message);
                 }
@@ -295,6 +429,18 @@ where
                 shell.invalidate_layout();
                 shell.request_redraw();
             }
+        } else {
+            Widget::<Message, Theme, Renderer>::update(
+                &mut self.content_label,
+                &mut tree.children[LABEL_CHILD],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
         }
 
         handle_enter_event::<Message, Renderer>(
@@ -317,13 +463,25 @@ where
     ) -> mouse::Interaction {
         let state: &State = tree.state.downcast_ref();
 
-        let content_interaction = self.get_widget(state.is_edit_mode).mouse_interaction(
-            &tree.children[state.get_child_index()],
-            layout,
-            cursor,
-            viewport,
-            renderer,
-        );
+        let content_interaction = if state.is_edit_mode {
+            Widget::<String, Theme, Renderer>::mouse_interaction(
+                &self.content_input,
+                &tree.children[INPUT_CHILD],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        } else {
+            Widget::<Message, Theme, Renderer>::mouse_interaction(
+                &self.content_label,
+                &tree.children[LABEL_CHILD],
+                layout,
+                cursor,
+                viewport,
+                renderer,
+            )
+        };
 
         match (self.interaction, content_interaction) {
             (Some(interaction), mouse::Interaction::None) if cursor.is_over(layout.bounds()) => {
@@ -332,25 +490,6 @@ where
             _ => content_interaction,
         }
     }
-
-    fn overlay<'b>(
-        &'b mut self,
-        tree: &'b mut Tree,
-        layout: Layout<'b>,
-        renderer: &Renderer,
-        viewport: &Rectangle,
-        translation: Vector,
-    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
-        let state: &State = tree.state.downcast_ref();
-
-        self.get_widget_mut(state.is_edit_mode).overlay(
-            &mut tree.children[state.get_child_index()],
-            layout,
-            renderer,
-            viewport,
-            translation,
-        )
-    }
 }
 
 impl<'a, Message, Theme, Renderer> From<DynamicTextInput<'a, Message, Theme, Renderer>>
@@ -421,6 +560,34 @@ fn handle_enter_event<Message: Clone, Renderer: text::Renderer>(
     }
 }
 
+fn apply_requested_edit_mode<Renderer: text::Renderer>(tree: &mut Tree, editing: bool) {
+    let state: &mut State = tree.state.downcast_mut();
+
+    if state.edit_requested == editing {
+        return;
+    }
+    state.edit_requested = editing;
+
+    if state.is_edit_mode == editing {
+        return;
+    }
+    state.is_edit_mode = editing;
+
+    // leaving edit mode from outside follows an event that already ended
+    // the edit in `update`, e.g. a click elsewhere or Tab, and committed the draft
+    state.draft = None;
+
+    let input_state: &mut text_input::State<Renderer::Paragraph> =
+        tree.children[INPUT_CHILD].state.downcast_mut();
+
+    if editing {
+        input_state.focus();
+        input_state.select_all();
+    } else {
+        input_state.unfocus();
+    }
+}
+
 fn enter_edit_mode<Message: Clone, Renderer: text::Renderer>(
     tree: &mut Tree,
     shell: &mut Shell<'_, Message>,
@@ -428,6 +595,7 @@ fn enter_edit_mode<Message: Clone, Renderer: text::Renderer>(
 ) {
     let state: &mut State = tree.state.downcast_mut();
     state.is_edit_mode = true;
+    state.draft = None;
 
     if let Some(message) = on_enter {
         shell.publish(message);
@@ -436,7 +604,7 @@ fn enter_edit_mode<Message: Clone, Renderer: text::Renderer>(
     shell.invalidate_layout();
 
     let input_state: &mut text_input::State<Renderer::Paragraph> =
-        tree.children[state.get_child_index()].state.downcast_mut();
+        tree.children[INPUT_CHILD].state.downcast_mut();
 
     input_state.focus();
     input_state.select_all();
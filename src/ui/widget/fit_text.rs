@@ -0,0 +1,406 @@
+use iced::advanced::graphics::core::Element;
+use iced::advanced::text::{self, LineHeight, Paragraph, Shaping, Wrapping, paragraph};
+use iced::advanced::widget::{Operation, Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::alignment::{Horizontal, Vertical};
+use iced::time::Instant;
+use iced::{Color, Event, Length, Point, Rectangle, Size, Vector, window};
+use std::borrow::Cow;
+
+/// Seconds a scrolling text rests at its start before every pass.
+const MARQUEE_PAUSE: f32 = 2.0;
+/// Scroll speed in font sizes per second.
+const MARQUEE_SPEED: f32 = 2.0;
+/// Space between the end of a scrolling text and its repetition, in font sizes.
+const MARQUEE_GAP: f32 = 2.0;
+/// Width of the outline around the text, in font sizes.
+const OUTLINE_WIDTH: f32 = 0.03;
+/// Distance of the shadow from the text, in font sizes.
+const SHADOW_OFFSET: f32 = 0.05;
+
+/// What a [`FitText`] draws, reported through [`Operation::custom`] so that
+/// other widgets can draw the text themselves, e.g. turned or mirrored.
+#[derive(Debug, Clone)]
+pub struct TextRun<Font> {
+    pub content: String,
+    pub font: Font,
+    pub size: f32,
+    pub color: Color,
+    /// Horizontal positions of the text within its bounds, two while it scrolls.
+    pub offsets: Vec<f32>,
+    pub outline: Option<Color>,
+    pub shadow: Option<Color>,
+}
+
+impl<Font> TextRun<Font> {
+    pub fn layers(&self) -> Vec<(Vector, Color)> {
+        layers(self.size, self.color, self.outline, self.shadow)
+    }
+}
+
+/// Returns the offsets and colours a text is drawn with, back to front: the
+/// shadow, the outline as copies around the text and the text itself.
+fn layers(
+    size: f32,
+    color: Color,
+    outline: Option<Color>,
+    shadow: Option<Color>,
+) -> Vec<(Vector, Color)> {
+    let mut layers = Vec::new();
+
+    if let Some(shadow) = shadow {
+        let offset = (size * SHADOW_OFFSET).max(1.0);
+        layers.push((Vector::new(offset, offset), shadow));
+    }
+    if let Some(outline) = outline {
+        let width = (size * OUTLINE_WIDTH).max(1.0);
+        let diagonal = width * std::f32::consts::FRAC_1_SQRT_2;
+
+        layers.extend(
+            [
+                (width, 0.0),
+                (diagonal, diagonal),
+                (0.0, width),
+                (-diagonal, diagonal),
+                (-width, 0.0),
+                (-diagonal, -diagonal),
+                (0.0, -width),
+                (diagonal, -diagonal),
+            ]
+            .map(|(x, y)| (Vector::new(x, y), outline)),
+        );
+    }
+
+    layers.push((Vector::ZERO, color));
+    layers
+}
+
+/// A single line of text that shrinks until it fits its width. Texts that
+/// are still too wide at the minimum size are cut off with an ellipsis or,
+/// as a marquee, scroll through.
+#[allow(missing_debug_implementations)]
+pub struct FitText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    content: Cow<'a, str>,
+    size: f32,
+    min_size: f32,
+    font: Option<Renderer::Font>,
+    color: Color,
+    align_x: Horizontal,
+    width: Length,
+    marquee: bool,
+    outline: Option<Color>,
+    shadow: Option<Color>,
+}
+
+impl<'a, Renderer> FitText<'a, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    pub fn new(content: impl Into<Cow<'a, str>>, size: f32) -> Self {
+        Self {
+            content: content.into(),
+            size,
+            min_size: size,
+            font: None,
+            color: Color::WHITE,
+            align_x: Horizontal::Left,
+            width: Length::Shrink,
+            marquee: false,
+            outline: None,
+            shadow: None,
+        }
+    }
+
+    /// Sets the size the text may shrink to before it overflows.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size.min(self.size);
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn align_x(mut self, align_x: impl Into<Horizontal>) -> Self {
+        self.align_x = align_x.into();
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Scrolls texts that do not fit instead of cutting them off.
+    pub fn marquee(mut self, marquee: bool) -> Self {
+        self.marquee = marquee;
+        self
+    }
+
+    /// Draws an outline in the given colour around the text.
+    pub fn outline(mut self, outline: Option<Color>) -> Self {
+        self.outline = outline;
+        self
+    }
+
+    /// Draws a shadow in the given colour below and to the right of the text.
+    pub fn shadow(mut self, shadow: Option<Color>) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
+    /// Lays out the text on a single line at the given size and returns its width.
+    fn measure(
+        &self,
+        paragraph: &mut paragraph::Plain<Renderer::Paragraph>,
+        renderer: &Renderer,
+        content: &str,
+        size: f32,
+    ) -> f32 {
+        let _ = paragraph.update(text::Text {
+            content,
+            bounds: Size::INFINITE,
+            size: size.into(),
+            line_height: LineHeight::default(),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Left,
+            align_y: Vertical::Top,
+            shaping: Shaping::Advanced,
+            wrapping: Wrapping::None,
+        });
+
+        paragraph.min_width()
+    }
+
+    /// Cuts the text off at the longest prefix that fits with an ellipsis
+    /// and returns the shortened text and its width.
+    fn fit_ellipsis(
+        &self,
+        paragraph: &mut paragraph::Plain<Renderer::Paragraph>,
+        renderer: &Renderer,
+        size: f32,
+        max_width: f32,
+    ) -> (String, f32) {
+        let mut boundaries: Vec<usize> = self.content.char_indices().map(|(i, _)| i).collect();
+        boundaries.push(self.content.len());
+        let shortened = |end: usize| format!("{}…", self.content[..boundaries[end]].trim_end());
+
+        // binary search for the number of characters that still fit
+        let (mut fitting, mut too_long) = (0, boundaries.len() - 1);
+        while too_long - fitting > 1 {
+            let middle = (fitting + too_long) / 2;
+            if self.measure(paragraph, renderer, &shortened(middle), size) <= max_width {
+                fitting = middle;
+            } else {
+                too_long = middle;
+            }
+        }
+
+        let shortened = shortened(fitting);
+        let width = self.measure(paragraph, renderer, &shortened, size);
+        (shortened, width)
+    }
+
+    /// Returns the horizontal positions the text is drawn at within its bounds.
+    fn offsets(&self, state: &State<Renderer::Paragraph>, bounds: Rectangle) -> Vec<f32> {
+        let width = state.paragraph.min_width();
+
+        if !state.scrolling {
+            let x = match self.align_x {
+                Horizontal::Left => 0.0,
+                Horizontal::Center => (bounds.width - width) / 2.0,
+                Horizontal::Right => bounds.width - width,
+            };
+            return vec![x];
+        }
+
+        // the text is drawn twice, so its start follows right after its end
+        let distance = width + state.paragraph.raw().size().0 * MARQUEE_GAP;
+        vec![-state.offset, distance - state.offset]
+    }
+}
+
+struct State<P: Paragraph> {
+    paragraph: paragraph::Plain<P>,
+    /// The text cut off with an ellipsis, if it does not fit.
+    shortened: Option<String>,
+    /// Whether the text is wider than its bounds and scrolls.
+    scrolling: bool,
+    started: Option<Instant>,
+    offset: f32,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for FitText<'_, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Font: 'static,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            paragraph: paragraph::Plain::default(),
+            shortened: None,
+            scrolling: false,
+            started: None,
+            offset: 0.0,
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, Length::Shrink)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let max_width = limits.max().width;
+
+        // the width grows about linearly with the size, so this converges in a few steps
+        let mut size = self.size;
+        let mut width = self.measure(&mut state.paragraph, renderer, &self.content, size);
+        for _ in 0..4 {
+            if width <= max_width || size <= self.min_size {
+                break;
+            }
+
+            size = (size * max_width / width).max(self.min_size);
+            width = self.measure(&mut state.paragraph, renderer, &self.content, size);
+        }
+
+        state.scrolling = width > max_width && self.marquee;
+        state.shortened = None;
+        if width > max_width && !self.marquee {
+            let (shortened, shortened_width) =
+                self.fit_ellipsis(&mut state.paragraph, renderer, size, max_width);
+            state.shortened = Some(shortened);
+            width = shortened_width;
+        }
+        if !state.scrolling {
+            state.started = None;
+            state.offset = 0.0;
+        }
+
+        let intrinsic = Size::new(width.min(max_width), state.paragraph.min_height());
+        layout::Node::new(limits.resolve(self.width, Length::Shrink, intrinsic))
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        if !state.scrolling {
+            return;
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let started = *state.started.get_or_insert(*now);
+            let size = state.paragraph.raw().size().0;
+            let distance = state.paragraph.min_width() + size * MARQUEE_GAP;
+            let cycle = MARQUEE_PAUSE + distance / (size * MARQUEE_SPEED);
+
+            let elapsed = now.duration_since(started).as_secs_f32() % cycle;
+            state.offset = (elapsed - MARQUEE_PAUSE).max(0.0) * size * MARQUEE_SPEED;
+            shell.request_redraw();
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let mut run = TextRun {
+            content: state
+                .shortened
+                .clone()
+                .unwrap_or_else(|| self.content.to_string()),
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            size: state.paragraph.raw().size().0,
+            color: self.color,
+            offsets: self.offsets(state, layout.bounds()),
+            outline: self.outline,
+            shadow: self.shadow,
+        };
+
+        operation.custom(None, layout.bounds(), &mut run);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let size = state.paragraph.raw().size().0;
+        let offsets = self.offsets(state, bounds);
+
+        let draw = |renderer: &mut Renderer, clip: Rectangle| {
+            for (layer, color) in layers(size, self.color, self.outline, self.shadow) {
+                for x in &offsets {
+                    renderer.fill_paragraph(
+                        state.paragraph.raw(),
+                        Point::new(bounds.x + x, bounds.y) + layer,
+                        color,
+                        clip,
+                    );
+                }
+            }
+        };
+
+        if !state.scrolling {
+            draw(renderer, *viewport);
+            return;
+        }
+
+        let Some(clip) = bounds.intersection(viewport) else {
+            return;
+        };
+        renderer.with_layer(clip, |renderer| draw(renderer, clip));
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<FitText<'a, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Font: 'static,
+{
+    fn from(text: FitText<'a, Renderer>) -> Self {
+        Element::new(text)
+    }
+}
@@ -1,5 +1,10 @@
 pub mod canvas_toggle;
+pub mod click_outside;
 pub mod dynamic_text_input;
+pub mod fit_text;
+pub mod on_blur;
 pub mod power_button;
+pub mod projection;
 pub mod restart_button;
 pub mod suggestion_text_input;
+pub mod translate;
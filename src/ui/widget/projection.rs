@@ -0,0 +1,498 @@
+use crate::ui::widget::fit_text::TextRun;
+use iced::advanced::graphics::core::Element;
+use iced::advanced::graphics::geometry::Renderer as _;
+use iced::advanced::image;
+use iced::advanced::widget::{Operation, Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::widget::canvas::{Frame, Text};
+use iced::widget::text::Shaping;
+use iced::{Color, Event, Font, Length, Point, Radians, Rectangle, Renderer, Size, Vector, window};
+use std::any::Any;
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
+
+/// What a [`ProjectedImage`] draws, reported through [`Operation::custom`].
+#[derive(Debug, Clone)]
+pub struct ImageRun {
+    pub handle: image::Handle,
+    pub opacity: f32,
+}
+
+impl ImageRun {
+    pub fn new(handle: &image::Handle, opacity: f32) -> Self {
+        Self {
+            handle: handle.clone(),
+            opacity,
+        }
+    }
+}
+
+/// A rectangle filled with a colour, reported like an [`ImageRun`].
+#[derive(Debug, Clone)]
+pub struct FillRun {
+    pub color: Color,
+}
+
+/// Something the [`Projection`] draws itself, in the coordinates of its content.
+enum Run {
+    Text(Rectangle, TextRun<Font>),
+    Image(Rectangle, ImageRun),
+    Fill(Rectangle, FillRun),
+}
+
+/// Collects the texts and images of the content of a [`Projection`].
+struct Collector<'a> {
+    origin: Point,
+    runs: &'a mut Vec<Run>,
+}
+
+impl Operation for Collector<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&iced::advanced::widget::Id>,
+        bounds: Rectangle,
+        state: &mut dyn Any,
+    ) {
+        let bounds = bounds - Vector::new(self.origin.x, self.origin.y);
+
+        if let Some(text) = state.downcast_ref::<TextRun<Font>>() {
+            self.runs.push(Run::Text(bounds, text.clone()));
+        } else if let Some(image) = state.downcast_ref::<ImageRun>() {
+            self.runs.push(Run::Image(bounds, image.clone()));
+        } else if let Some(fill) = state.downcast_ref::<FillRun>() {
+            self.runs.push(Run::Fill(bounds, fill.clone()));
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    runs: Vec<Run>,
+    /// Horizontally flipped copies of the images shown while mirrored, by
+    /// id of the original, or `None` if it could not be decoded.
+    mirrored_images: HashMap<image::Id, Option<image::Handle>>,
+}
+
+impl State {
+    /// Flips the images that are collected for the first time and forgets
+    /// the ones no longer shown.
+    fn mirror_images(&mut self) {
+        let shown: Vec<&image::Handle> = self
+            .runs
+            .iter()
+            .filter_map(|run| match run {
+                Run::Image(_, image) => Some(&image.handle),
+                _ => None,
+            })
+            .collect();
+
+        self.mirrored_images
+            .retain(|id, _| shown.iter().any(|handle| handle.id() == *id));
+
+        for handle in shown {
+            self.mirrored_images
+                .entry(handle.id())
+                .or_insert_with(|| mirror(handle));
+        }
+    }
+}
+
+/// Returns a horizontally flipped copy of the image.
+fn mirror(handle: &image::Handle) -> Option<image::Handle> {
+    let decoded = match handle {
+        image::Handle::Bytes(_, bytes) => ::image::load_from_memory(bytes).map(|i| i.into_rgba8()),
+        image::Handle::Path(_, path) => ::image::open(path).map(|i| i.into_rgba8()),
+        image::Handle::Rgba {
+            width,
+            height,
+            pixels,
+            ..
+        } => {
+            return ::image::RgbaImage::from_raw(*width, *height, pixels.to_vec())
+                .map(|image| flipped_handle(&image));
+        }
+    };
+
+    match decoded {
+        Ok(image) => Some(flipped_handle(&image)),
+        Err(e) => {
+            println!("Failed to decode image to mirror: {}", e);
+            None
+        }
+    }
+}
+
+fn flipped_handle(image: &::image::RgbaImage) -> image::Handle {
+    let flipped = ::image::imageops::flip_horizontal(image);
+    image::Handle::from_rgba(flipped.width(), flipped.height(), flipped.into_raw())
+}
+
+/// Turns its content in quarter turns and mirrors it horizontally, for
+/// projectors that are mounted sideways, upside down or behind the screen.
+///
+/// The renderer can neither turn nor mirror text, so while turned or
+/// mirrored only texts, images and fills that report themselves, i.e.
+/// [`FitText`](crate::ui::widget::fit_text::FitText) and [`Projected`], are
+/// drawn, as geometry. Other backgrounds inside the content are not drawn.
+/// Texts are not clipped to their bounds there, so they should not scroll.
+#[allow(missing_debug_implementations)]
+pub struct Projection<'a, Message, Theme = iced::Theme> {
+    content: Element<'a, Message, Theme, Renderer>,
+    quarter_turns: u8,
+    mirrored: bool,
+}
+
+impl<'a, Message, Theme> Projection<'a, Message, Theme> {
+    /// Creates a [`Projection`] turning the content clockwise by the given
+    /// number of quarter turns.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        quarter_turns: u8,
+        mirrored: bool,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            quarter_turns: quarter_turns % 4,
+            mirrored,
+        }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.quarter_turns == 0 && !self.mirrored
+    }
+
+    fn angle(&self) -> f32 {
+        f32::from(self.quarter_turns) * FRAC_PI_2
+    }
+
+    /// Maps a point of the content onto the screen.
+    fn project(&self, bounds: Rectangle, content_size: Size, point: Point) -> Point {
+        let (sin, cos) = self.angle().sin_cos();
+        let x = point.x - content_size.width / 2.0;
+        let y = point.y - content_size.height / 2.0;
+
+        let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+        let x = if self.mirrored { -x } else { x };
+
+        Point::new(bounds.center_x() + x, bounds.center_y() + y)
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for Projection<'_, Message, Theme> {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = limits.width(Length::Fill).height(Length::Fill).max();
+        let turned = if self.quarter_turns % 2 == 1 {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        };
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            &layout::Limits::new(Size::ZERO, turned),
+        );
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        // drawing has no mutable access to the content, so what it draws is
+        // collected right before every frame
+        if self.is_identity() || !matches!(event, Event::Window(window::Event::RedrawRequested(_)))
+        {
+            return;
+        }
+
+        let mut runs = Vec::new();
+        self.content.as_widget_mut().operate(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            &mut Collector {
+                origin: layout.position(),
+                runs: &mut runs,
+            },
+        );
+        let state = tree.state.downcast_mut::<State>();
+        state.runs = runs;
+        if self.mirrored {
+            state.mirror_images();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+        if self.is_identity() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+            return;
+        }
+
+        let bounds = layout.bounds();
+        let content_size = if self.quarter_turns % 2 == 1 {
+            Size::new(bounds.height, bounds.width)
+        } else {
+            bounds.size()
+        };
+        let state = tree.state.downcast_ref::<State>();
+        let mut frame = Frame::with_bounds(renderer, bounds);
+
+        // images can be turned, but not mirrored, so mirrored ones are drawn
+        // flipped beforehand and turned the other way round
+        for run in &state.runs {
+            let Run::Image(rect, image) = run else {
+                continue;
+            };
+
+            let handle = if self.mirrored {
+                match state.mirrored_images.get(&image.handle.id()) {
+                    Some(Some(flipped)) => flipped,
+                    _ => continue,
+                }
+            } else {
+                &image.handle
+            };
+
+            let center = self.project(bounds, content_size, rect.center());
+            let angle = if self.mirrored {
+                -self.angle()
+            } else {
+                self.angle()
+            };
+
+            frame.draw_image(
+                Rectangle::new(
+                    center - Vector::new(rect.width / 2.0, rect.height / 2.0),
+                    rect.size(),
+                ),
+                image::Image::new(handle)
+                    .rotation(Radians(angle))
+                    .opacity(image.opacity),
+            );
+        }
+
+        frame.translate(Vector::new(bounds.center_x(), bounds.center_y()));
+        if self.mirrored {
+            frame.scale_nonuniform(Vector::new(-1.0, 1.0));
+        }
+        frame.rotate(Radians(self.angle()));
+        frame.translate(Vector::new(
+            -content_size.width / 2.0,
+            -content_size.height / 2.0,
+        ));
+
+        for run in &state.runs {
+            if let Run::Fill(rect, fill) = run {
+                frame.fill_rectangle(rect.position(), rect.size(), fill.color);
+            }
+        }
+
+        // texts cannot be clipped to their bounds here, as clipping ignores
+        // the transformation of the frame
+        for run in &state.runs {
+            let Run::Text(rect, text) = run else {
+                continue;
+            };
+
+            for (layer, color) in text.layers() {
+                for x in &text.offsets {
+                    frame.fill_text(Text {
+                        content: text.content.clone(),
+                        position: Point::new(rect.x + x, rect.y) + layer,
+                        color,
+                        size: text.size.into(),
+                        font: text.font,
+                        shaping: Shaping::Advanced,
+                        ..Text::default()
+                    });
+                }
+            }
+        }
+
+        renderer.draw_geometry(frame.into_geometry());
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+}
+
+impl<'a, Message, Theme> From<Projection<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+{
+    fn from(projection: Projection<'a, Message, Theme>) -> Self {
+        Element::new(projection)
+    }
+}
+
+/// Content that reports what it draws, an [`ImageRun`] or a [`FillRun`],
+/// to a surrounding [`Projection`], so it is also drawn while the
+/// projection is turned or mirrored.
+#[allow(missing_debug_implementations)]
+pub struct Projected<'a, R, Message, Theme = iced::Theme> {
+    content: Element<'a, Message, Theme, Renderer>,
+    run: R,
+}
+
+pub type ProjectedImage<'a, Message, Theme = iced::Theme> = Projected<'a, ImageRun, Message, Theme>;
+
+impl<'a, R, Message, Theme> Projected<'a, R, Message, Theme> {
+    pub fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>, run: R) -> Self {
+        Self {
+            content: content.into(),
+            run,
+        }
+    }
+}
+
+impl<R: 'static, Message, Theme> Widget<Message, Theme, Renderer>
+    for Projected<'_, R, Message, Theme>
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.custom(None, layout.bounds(), &mut self.run);
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+}
+
+impl<'a, R, Message, Theme> From<Projected<'a, R, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    R: 'static,
+    Message: 'a,
+    Theme: 'a,
+{
+    fn from(projected: Projected<'a, R, Message, Theme>) -> Self {
+        Element::new(projected)
+    }
+}
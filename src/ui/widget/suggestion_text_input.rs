@@ -137,7 +137,10 @@ where
     on_option_hovered: Option<Box<dyn Fn(T) -> Message>>,
     on_open: Option<Message>,
     on_close: Option<Message>,
+    on_cancel: Option<Message>,
     on_input: Option<Box<dyn Fn(String) -> Message>>,
+    on_submit: Option<Box<dyn Fn(String) -> Message>>,
+    editing: bool,
     padding: Padding,
     size: Option<f32>,
     text_shaping: text::Shaping,
@@ -174,8 +177,11 @@ where
             on_selected: Box::new(on_selected),
             on_option_hovered: None,
             on_input: None,
+            on_submit: None,
             on_open: None,
             on_close: None,
+            on_cancel: None,
+            editing: false,
             padding: text_input::DEFAULT_PADDING,
             size: None,
             text_shaping: text::Shaping::default(),
@@ -191,6 +197,14 @@ where
         self
     }
 
+    /// Sets the message that should be produced with the typed text when the
+    /// [`TextInput`] is left without selecting an option or cancelling, or when
+    /// enter is pressed while no option matches.
+    pub fn on_submit(mut self, on_submit: impl Fn(String) -> Message + 'static) -> Self {
+        self.on_submit = Some(Box::new(on_submit));
+        self
+    }
+
     /// Sets the message that will be produced when an option of the
     /// [`SuggestionTextInput`] is hovered using the arrow keys.
     pub fn on_option_hovered(mut self, on_option_hovered: impl Fn(T) -> Message + 'static) -> Self {
@@ -212,6 +226,19 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the input is left with escape.
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Focuses the [`TextInput`] when this changes to `true` and unfocuses it
+    /// when it changes to `false`.
+    pub fn editing(mut self, editing: bool) -> Self {
+        self.editing = editing;
+        self
+    }
+
     /// Sets the [`Padding`] of the [`SuggestionTextInput`].
     pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
         self.padding = padding.into();
@@ -351,7 +378,7 @@ where
         let option_matchers = build_matchers(&options);
 
         let filtered_options = Filtered::new(
-            search(&options, &option_matchers, &value)
+            search_ranked(&options, &option_matchers, &value)
                 .cloned()
                 .collect(),
         );
@@ -382,7 +409,7 @@ where
         self.options.push(new_option);
 
         inner.filtered_options = Filtered::new(
-            search(&self.options, &inner.option_matchers, &inner.value)
+            search_ranked(&self.options, &inner.option_matchers, &inner.value)
                 .cloned()
                 .collect(),
         );
@@ -459,6 +486,7 @@ where
 
 struct Menu<T> {
     menu: menu::State,
+    focus_requested: bool,
     hovered_option: Option<usize>,
     new_selection: Option<T>,
     filtered_options: Filtered<T>,
@@ -487,6 +515,22 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let menu = tree.state.downcast_mut::<Menu<T>>();
+        if menu.focus_requested != self.editing {
+            menu.focus_requested = self.editing;
+
+            let text_input_state = tree.children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+            if self.editing {
+                text_input_state.focus();
+                text_input_state.select_all();
+            } else {
+                text_input_state.unfocus();
+            }
+        }
+
         let is_focused = {
             let text_input_state = tree.children[0]
                 .state
@@ -545,6 +589,7 @@ where
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(Menu::<T> {
             menu: menu::State::new(),
+            focus_requested: false,
             filtered_options: Filtered::empty(),
             hovered_option: Some(0),
             new_selection: None,
@@ -580,6 +625,21 @@ where
             text_input_state.is_focused()
         };
 
+        // whether the typed text is discarded when the input loses focus
+        let mut discard_value = false;
+        let mut submit_value = false;
+
+        if started_focused
+            && let Some(on_cancel) = self.on_cancel.clone()
+            && let Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(key::Named::Escape),
+                ..
+            }) = event
+        {
+            shell.publish(on_cancel);
+            discard_value = true;
+        }
+
         // Create a new list of local messages
         let mut local_messages = Vec::new();
         let mut local_shell = Shell::new(&mut local_messages);
@@ -619,7 +679,7 @@ where
                 state.value = new_value;
 
                 state.filtered_options.update(
-                    search(&self.state.options, &state.option_matchers, &state.value)
+                    search_ranked(&self.state.options, &state.option_matchers, &state.value)
                         .cloned()
                         .collect(),
                 );
@@ -659,6 +719,8 @@ where
                                 && let Some(option) = state.filtered_options.options.get(*index)
                             {
                                 menu.new_selection = Some(option.clone());
+                            } else {
+                                submit_value = true;
                             }
 
                             shell.capture_event();
@@ -724,6 +786,8 @@ where
         // If the overlay menu has selected something
         self.state.with_inner_mut(|state| {
             if let Some(selection) = menu.new_selection.take() {
+                discard_value = true;
+
                 // Clear the value and reset the options and menu
                 state.value = String::new();
                 state.filtered_options.update(self.state.options.clone());
@@ -749,6 +813,13 @@ where
             }
         });
 
+        if submit_value {
+            tree.children[0]
+                .state
+                .downcast_mut::<text_input::State<Renderer::Paragraph>>()
+                .unfocus();
+        }
+
         let is_focused = {
             let text_input_state = tree.children[0]
                 .state
@@ -765,8 +836,18 @@ where
                 if let Some(on_open) = self.on_open.take() {
                     shell.publish(on_open);
                 }
-            } else if let Some(on_close) = self.on_close.take() {
-                shell.publish(on_close);
+            } else {
+                if !discard_value && let Some(on_submit) = &self.on_submit {
+                    let value = self.state.value();
+
+                    if value != self.selection.to_string() {
+                        shell.publish(on_submit(value));
+                    }
+                }
+
+                if let Some(on_close) = self.on_close.take() {
+                    shell.publish(on_close);
+                }
             }
         }
     }
@@ -887,7 +968,7 @@ pub trait Catalog: text_input::Catalog + menu::Catalog {
 
 impl Catalog for Theme {}
 
-fn search<'a, T, A>(
+pub(crate) fn search<'a, T, A>(
     options: impl IntoIterator<Item = T> + 'a,
     option_matchers: impl IntoIterator<Item = &'a A> + 'a,
     query: &'a str,
@@ -895,11 +976,7 @@ fn search<'a, T, A>(
 where
     A: AsRef<str> + 'a,
 {
-    let query: Vec<String> = query
-        .to_lowercase()
-        .split(|c: char| !c.is_ascii_alphanumeric())
-        .map(String::from)
-        .collect();
+    let query = split_query(query);
 
     options
         .into_iter()
@@ -914,6 +991,57 @@ where
         })
 }
 
+/// Like [`search`], but orders the results so that options exactly matching the
+/// query come first, followed by options starting with the query.
+fn search_ranked<'a, T, A>(
+    options: impl IntoIterator<Item = T> + 'a,
+    option_matchers: impl IntoIterator<Item = &'a A> + 'a,
+    query: &'a str,
+) -> impl Iterator<Item = T> + 'a
+where
+    T: 'a,
+    A: AsRef<str> + 'a,
+{
+    let query_parts = split_query(query);
+    let query_matcher = build_matcher(query);
+
+    let mut ranked: Vec<(u8, T)> = options
+        .into_iter()
+        .zip(option_matchers)
+        .filter(|(_, matcher)| {
+            query_parts
+                .iter()
+                .all(|part| matcher.as_ref().contains(part))
+        })
+        .map(|(option, matcher)| {
+            let matcher = matcher.as_ref();
+            let rank = if query_matcher.is_empty() {
+                2
+            } else if matcher == query_matcher {
+                0
+            } else if matcher.starts_with(&query_matcher) {
+                1
+            } else {
+                2
+            };
+
+            (rank, option)
+        })
+        .collect();
+
+    // stable sort keeps the original order within each rank
+    ranked.sort_by_key(|(rank, _)| *rank);
+    ranked.into_iter().map(|(_, option)| option)
+}
+
+fn split_query(query: &str) -> Vec<String> {
+    query
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(String::from)
+        .collect()
+}
+
 fn build_matchers<'a, T>(options: impl IntoIterator<Item = T> + 'a) -> Vec<String>
 where
     T: Display + 'a,
@@ -921,7 +1049,7 @@ where
     options.into_iter().map(build_matcher).collect()
 }
 
-fn build_matcher<T>(option: T) -> String
+pub(crate) fn build_matcher<T>(option: T) -> String
 where
     T: Display,
 {
@@ -929,3 +1057,26 @@ where
     matcher.retain(|c| c.is_ascii_alphanumeric());
     matcher.to_lowercase()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::widget::suggestion_text_input::{build_matchers, search_ranked};
+
+    #[test]
+    fn exact_matches_are_ranked_first() {
+        let options = vec![
+            "Cha Cha Cha Mix",
+            "Wiener Walzer",
+            "Cha Cha Cha",
+            "Chachacha Remix",
+        ];
+        let matchers = build_matchers(&options);
+
+        let result: Vec<_> = search_ranked(&options, &matchers, "cha cha cha").collect();
+
+        assert_eq!(
+            result,
+            vec![&"Cha Cha Cha", &"Cha Cha Cha Mix", &"Chachacha Remix"]
+        );
+    }
+}